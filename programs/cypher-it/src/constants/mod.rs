@@ -3,15 +3,98 @@ pub const ANCHOR_DISCRIMINATOR_SIZE: usize = 8;
 // String length limits
 pub const MAX_CHANNEL_NAME_LENGTH: usize = 64;
 pub const MAX_CHANNEL_DESCRIPTION_LENGTH: usize = 512;
+pub const MAX_DISPLAY_NAME_LENGTH: usize = 50;
+pub const MAX_BIO_LENGTH: usize = 200;
+pub const MAX_AVATAR_URI_LENGTH: usize = 200;
 
 // Platform fee limits
-pub const MAX_PLATFORM_FEE: u64 = 50; // 50%
+pub const BPS_DENOMINATOR: u64 = 10_000;
+pub const BPS_PER_PERCENT: u64 = 100;
+pub const DEFAULT_PLATFORM_FEE_BPS: u64 = 500; // 5%
+pub const MAX_PLATFORM_FEE_BPS: u64 = 5_000; // 50%
+pub const MAX_FEE_TIERS: usize = 4;
+pub const MAX_TRANSFER_ROYALTY: u64 = 50; // 50%
+
+// Emergency pause bits, by subsystem
+pub const PAUSE_JOINS: u8 = 1 << 0; // Channel creation, joins and claims
+pub const PAUSE_POLLS: u8 = 1 << 1; // Poll creation, voting and execution
+pub const PAUSE_WITHDRAWALS: u8 = 1 << 2;
+pub const PAUSE_GENERAL: u8 = 1 << 3; // Channel admin, moderation, messaging, profiles and migrations
+pub const PAUSE_ALL: u8 = PAUSE_JOINS | PAUSE_POLLS | PAUSE_WITHDRAWALS | PAUSE_GENERAL;
+
+// Account layout versions, bumped whenever a struct gains fields
+pub const CONFIG_VERSION: u8 = 1;
+pub const CHANNEL_VERSION: u8 = 3;
+pub const MEMBERSHIP_VERSION: u8 = 4;
+pub const POLL_VERSION: u8 = 4;
+pub const PROFILE_VERSION: u8 = 1;
 
 // Seeds for PDAs
 pub const STATE_SEED: &[u8] = b"state";
+pub const TREASURY_SEED: &[u8] = b"treasury";
+pub const FEE_SCHEDULE_SEED: &[u8] = b"fee_schedule";
 pub const CHANNEL_SEED: &[u8] = b"channel";
 pub const MEMBERSHIP_SEED: &[u8] = b"membership";
+pub const MEMBER_STATS_SEED: &[u8] = b"member_stats";
 pub const NFT_MINT_SEED: &[u8] = b"nft_mint";
+pub const BAN_SEED: &[u8] = b"ban";
+pub const PLATFORM_BAN_SEED: &[u8] = b"platform_ban";
+pub const VOTE_DELEGATION_SEED: &[u8] = b"vote_delegation";
+pub const PROFILE_SEED: &[u8] = b"profile";
+pub const USERNAME_SEED: &[u8] = b"username";
+pub const MESSAGE_LOG_SEED: &[u8] = b"message_log";
+pub const MESSAGE_BATCH_SEED: &[u8] = b"message_batch";
+pub const CHANNEL_MESSAGES_SEED: &[u8] = b"channel_messages";
+pub const CHANNEL_PINS_SEED: &[u8] = b"channel_pins";
+pub const MESSAGE_REACTIONS_SEED: &[u8] = b"message_reactions";
 
 // Minimum costs
-pub const MIN_CHANNEL_COST: u64 = 1_000_000; // 0.001 SOL in lamports
\ No newline at end of file
+pub const MIN_CHANNEL_COST: u64 = 1_000_000; // 0.001 SOL in lamports
+
+// Membership NFT metadata (Metaplex limits)
+pub const MEMBERSHIP_NFT_SYMBOL: &str = "SHIPYARD";
+pub const MAX_NFT_NAME_LENGTH: usize = 32;
+pub const MAX_NFT_URI_LENGTH: usize = 200;
+
+// Poll types
+pub const POLL_TYPE_KICK: u8 = 0;
+pub const POLL_TYPE_MUTE: u8 = 1;
+pub const POLL_TYPE_NORMAL: u8 = 2;
+
+// Poll rate limits; channels may tighten them with set_poll_settings
+pub const MIN_POLL_DURATION: i64 = 60;
+pub const MAX_POLL_DURATION: i64 = 30 * 24 * 60 * 60; // 30 days
+pub const DEFAULT_MIN_POLL_DURATION: i64 = 60 * 60; // 1 hour
+pub const DEFAULT_MAX_POLL_DURATION: i64 = 7 * 24 * 60 * 60; // 7 days
+pub const DEFAULT_MAX_OPEN_POLLS: u16 = 5;
+pub const MAX_POLL_VOTERS: usize = 100; // Poll::voted max_len
+pub const MAX_DELEGATED_VOTES: usize = 50; // Poll::delegated_votes max_len, keeps a poll under the 10KB init limit
+
+// Moderation
+pub const MAX_MODERATORS: usize = 5;
+pub const PROTECTED_MODERATORS: u8 = 1 << 0;
+pub const PROTECTED_ROLES_ALL: u8 = PROTECTED_MODERATORS;
+pub const KICK_BAN_DURATION: i64 = 7 * 24 * 60 * 60; // 7 days before a kicked member can rejoin
+pub const MUTE_DURATION: i64 = 24 * 60 * 60; // 1 day in seconds
+
+// Reputation
+pub const REPUTATION_PER_VOTE: u64 = 1;
+pub const REPUTATION_PER_QUORUM_POLL: u64 = 5;
+pub const REPUTATION_KICK_PENALTY: u64 = 20;
+pub const REPUTATION_MUTE_PENALTY: u64 = 10;
+pub const MAX_TENURE_REPUTATION: u64 = 30; // 1 point per day of membership
+pub const MIN_MEMBER_QUORUM: u64 = 3; // Floor for the member-majority quorum
+
+// Message anchoring
+pub const MAX_MERKLE_PROOF_DEPTH: usize = 32;
+pub const MAX_MESSAGE_BATCH_SIZE: u64 = 10_000;
+
+// On-chain chat
+pub const MAX_MESSAGE_LENGTH: usize = 280;
+pub const MESSAGE_RING_CAPACITY: usize = 24;
+
+// Pins and reactions
+pub const MAX_PINNED_MESSAGES: usize = 10;
+pub const MAX_EMOJI_LENGTH: usize = 16;
+pub const MAX_REACTION_KINDS: usize = 10;
+pub const MAX_REACTORS: usize = 64;
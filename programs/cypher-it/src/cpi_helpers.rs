@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::constants::{BAN_SEED, MEMBERSHIP_SEED, PLATFORM_BAN_SEED};
+
+// Address of the Membership PDA partner programs must pass to `verify_membership`
+pub fn membership_address(channel_id: u64, member: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[MEMBERSHIP_SEED, channel_id.to_le_bytes().as_ref(), member.as_ref()],
+        &crate::ID,
+    )
+    .0
+}
+
+// Ban PDAs partner programs must pass alongside the membership, whether or not they exist
+pub fn ban_addresses(channel_id: u64, member: &Pubkey) -> (Pubkey, Pubkey) {
+    let ban = Pubkey::find_program_address(
+        &[BAN_SEED, channel_id.to_le_bytes().as_ref(), member.as_ref()],
+        &crate::ID,
+    )
+    .0;
+    let platform_ban = Pubkey::find_program_address(
+        &[PLATFORM_BAN_SEED, member.as_ref()],
+        &crate::ID,
+    )
+    .0;
+    (ban, platform_ban)
+}
+
+// Fails unless `member` is a joined, unmuted, unbanned member of channel `channel_id`
+pub fn verify_membership<'info>(
+    program: AccountInfo<'info>,
+    membership: AccountInfo<'info>,
+    ban: AccountInfo<'info>,
+    platform_ban: AccountInfo<'info>,
+    channel_id: u64,
+    member: Pubkey,
+) -> Result<()> {
+    crate::cpi::verify_membership(
+        CpiContext::new(
+            program,
+            crate::cpi::accounts::VerifyMembershipCtx {
+                membership,
+                ban,
+                platform_ban,
+            },
+        ),
+        channel_id,
+        member,
+    )
+}
@@ -91,4 +91,232 @@ pub enum ErrorCode {
 
     #[msg("Channel is Invalid")]
     InvalidChannel,
+
+    #[msg("Poll has not reached its end time")]
+    PollStillActive,
+
+    #[msg("Poll has not ended")]
+    PollNotEnded,
+
+    #[msg("Poll did not pass")]
+    PollNotPassed,
+
+    #[msg("Poll outcome has already been applied")]
+    PollAlreadyExecuted,
+
+    #[msg("Only the poll creator or channel creator can delegate this poll")]
+    UnauthorizedDelegation,
+
+    #[msg("Poll can only be undelegated after it ends or by the channel creator")]
+    UnauthorizedUndelegation,
+
+    #[msg("Only the channel relayer or a member can commit messages")]
+    UnauthorizedMessageCommit,
+
+    #[msg("Message batch sequence range is invalid")]
+    InvalidSequenceRange,
+
+    #[msg("Merkle proof is too long")]
+    MerkleProofTooLong,
+
+    #[msg("Message sequence is outside the batch range")]
+    MessageOutOfBatchRange,
+
+    #[msg("Merkle proof is invalid")]
+    InvalidMerkleProof,
+
+    #[msg("Message cannot be empty")]
+    EmptyMessage,
+
+    #[msg("Message is too long")]
+    MessageTooLong,
+
+    #[msg("Member is muted in this channel")]
+    MemberMuted,
+
+    #[msg("Only channel creator or moderators can perform this action")]
+    UnauthorizedModerator,
+
+    #[msg("User is already a moderator")]
+    ModeratorAlreadyAdded,
+
+    #[msg("Too many moderators for channel")]
+    TooManyModerators,
+
+    #[msg("Moderator not found")]
+    ModeratorNotFound,
+
+    #[msg("Message is already pinned")]
+    MessageAlreadyPinned,
+
+    #[msg("Message is not pinned")]
+    MessageNotPinned,
+
+    #[msg("Pinned message limit reached")]
+    PinLimitReached,
+
+    #[msg("Invalid emoji")]
+    InvalidEmoji,
+
+    #[msg("Too many different reactions on this message")]
+    TooManyReactionKinds,
+
+    #[msg("Too many reactions on this message")]
+    TooManyReactions,
+
+    #[msg("Display name is too long")]
+    DisplayNameTooLong,
+
+    #[msg("Display name cannot be empty")]
+    EmptyDisplayName,
+
+    #[msg("Display name may only contain letters, digits, spaces, '_', '-' and '.'")]
+    InvalidDisplayName,
+
+    #[msg("Insufficient reputation for this action")]
+    InsufficientReputation,
+
+    #[msg("Poll reputation has already been settled")]
+    PollReputationAlreadySettled,
+
+    #[msg("Membership NFT accounts are required for soulbound channels")]
+    MissingMembershipNft,
+
+    #[msg("Membership NFT does not match the membership")]
+    InvalidMembershipNft,
+
+    #[msg("Channel has reached its maximum number of members")]
+    ChannelFull,
+
+    #[msg("Memberships in this channel are not transferable")]
+    MembershipNotTransferable,
+
+    #[msg("Soulbound channels cannot have transferable memberships")]
+    InvalidMembershipPolicy,
+
+    #[msg("Transfer royalty cannot exceed maximum allowed percentage")]
+    TransferRoyaltyExceedsMaximum,
+
+    #[msg("Signer does not hold the membership NFT")]
+    NotNftHolder,
+
+    #[msg("Membership is already held by this wallet")]
+    CannotClaimOwnMembership,
+
+    #[msg("Bio is too long")]
+    BioTooLong,
+
+    #[msg("Avatar URI is too long")]
+    AvatarUriTooLong,
+
+    #[msg("Treasury balance is insufficient for this withdrawal")]
+    InsufficientTreasuryBalance,
+
+    #[msg("Too many fee tiers")]
+    TooManyFeeTiers,
+
+    #[msg("Fee tiers must be sorted by strictly ascending minimum cost")]
+    FeeTiersNotSorted,
+
+    #[msg("Signer is not the pending owner")]
+    NotPendingOwner,
+
+    #[msg("Program is paused")]
+    ProgramPaused,
+
+    #[msg("Guardian may only add pause bits")]
+    UnauthorizedUnpause,
+
+    #[msg("Invalid pause flags")]
+    InvalidPauseFlags,
+
+    #[msg("Account is not a program account of the expected type")]
+    InvalidMigrationAccount,
+
+    #[msg("Account is already at the current layout version")]
+    AccountAlreadyMigrated,
+
+    #[msg("Wallet is banned")]
+    WalletBanned,
+
+    #[msg("Ban duration cannot be negative")]
+    InvalidBanDuration,
+
+    #[msg("Invalid ban account")]
+    InvalidBan,
+
+    #[msg("Ban account is required to kick")]
+    MissingBanAccount,
+
+    #[msg("Poll duration is outside the channel's allowed range")]
+    InvalidPollDuration,
+
+    #[msg("Channel has too many open polls")]
+    TooManyOpenPolls,
+
+    #[msg("Wait for the poll cooldown before creating another poll")]
+    PollCooldownActive,
+
+    #[msg("Invalid poll settings")]
+    InvalidPollSettings,
+
+    #[msg("Poll deposit already settled")]
+    PollDepositAlreadySettled,
+
+    #[msg("Channel vault balance is insufficient for this withdrawal")]
+    InsufficientChannelVaultBalance,
+
+    #[msg("Poll target is not a member of this channel")]
+    TargetNotMember,
+
+    #[msg("Cannot create a moderation poll against yourself")]
+    CannotTargetSelf,
+
+    #[msg("Poll target holds a protected role")]
+    ProtectedTarget,
+
+    #[msg("Target already has an open moderation poll")]
+    DuplicateTargetPoll,
+
+    #[msg("Invalid protected roles")]
+    InvalidProtectedRoles,
+
+    #[msg("Members who joined after the poll started cannot vote in it")]
+    JoinedAfterPollStart,
+
+    #[msg("Cannot delegate your vote to yourself")]
+    CannotDelegateToSelf,
+
+    #[msg("Vote delegate is not a member of this channel")]
+    DelegateNotMember,
+
+    #[msg("Vote delegate has delegated their own vote")]
+    DelegationChain,
+
+    #[msg("Invalid vote delegation")]
+    InvalidVoteDelegation,
+
+    #[msg("Username is already taken")]
+    UsernameTaken,
+
+    #[msg("Profile already holds a username")]
+    UsernameAlreadyClaimed,
+
+    #[msg("Member is the target of an open moderation poll")]
+    TargetOfOpenPoll,
+
+    #[msg("Image URL is too long for NFT metadata")]
+    ImageUrlTooLong,
+
+    #[msg("Poll has no room for more voters")]
+    PollVoterLimitReached,
+
+    #[msg("Poll has no room for more delegated votes")]
+    DelegatedVoteLimitReached,
+
+    #[msg("Poll must be migrated before it can count delegated votes")]
+    PollNeedsMigration,
+
+    #[msg("Members holding vote delegations cannot delegate their own vote")]
+    DelegatorHoldsDelegations,
 }
\ No newline at end of file
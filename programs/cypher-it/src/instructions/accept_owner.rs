@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::constants::STATE_SEED;
+use crate::errors::ErrorCode;
+use crate::states::Config;
+
+pub fn accept_owner(ctx: Context<AcceptOwnerCtx>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let new_owner = &ctx.accounts.new_owner;
+
+    if config.pending_owner != Some(new_owner.key()) {
+        return Err(ErrorCode::NotPendingOwner.into());
+    }
+
+    let old_owner = config.owner;
+    config.owner = new_owner.key();
+    config.pending_owner = None;
+
+    emit!(OwnershipTransferred {
+        old_owner,
+        new_owner: new_owner.key(),
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptOwnerCtx<'info> {
+    #[account(
+        mut,
+        seeds = [STATE_SEED],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+
+    pub new_owner: Signer<'info>,
+}
+
+#[event]
+pub struct OwnershipTransferred {
+    pub old_owner: Pubkey,
+    pub new_owner: Pubkey,
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use crate::constants::{CHANNEL_SEED, MAX_MODERATORS, PAUSE_GENERAL, STATE_SEED};
+use crate::errors::ErrorCode;
+use crate::states::{Channel, Config};
+
+pub fn add_moderator(ctx: Context<AddModeratorCtx>, channel_id: u64, moderator: Pubkey) -> Result<()> {
+    let channel = &mut ctx.accounts.channel;
+    let creator = &ctx.accounts.creator;
+
+    if channel.is_moderator(&moderator) {
+        return Err(ErrorCode::ModeratorAlreadyAdded.into());
+    }
+
+    if channel.moderators.len() >= MAX_MODERATORS {
+        return Err(ErrorCode::TooManyModerators.into());
+    }
+
+    channel.moderators.push(moderator);
+    channel.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(ModeratorAdded {
+        channel_id,
+        creator: creator.key(),
+        moderator,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(channel_id: u64)]
+pub struct AddModeratorCtx<'info> {
+    #[account(
+        seeds = [STATE_SEED],
+        bump,
+        constraint = !config.is_paused(PAUSE_GENERAL) @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [
+            CHANNEL_SEED,
+            channel_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        constraint = channel.creator == creator.key() @ ErrorCode::UnauthorizedChannelUpdate
+    )]
+    pub channel: Account<'info, Channel>,
+
+    pub creator: Signer<'info>,
+}
+
+#[event]
+pub struct ModeratorAdded {
+    pub channel_id: u64,
+    pub creator: Pubkey,
+    pub moderator: Pubkey,
+}
@@ -0,0 +1,210 @@
+// instructions/apply_poll_outcome.rs
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::constants::{
+    BAN_SEED,
+    CHANNEL_SEED,
+    KICK_BAN_DURATION,
+    MEMBERSHIP_SEED,
+    MEMBER_STATS_SEED,
+    MUTE_DURATION,
+    PAUSE_POLLS,
+    POLL_TYPE_KICK,
+    POLL_TYPE_MUTE,
+    STATE_SEED,
+};
+use crate::errors::ErrorCode;
+use crate::states::{Channel, Config, MemberStats, Membership, Poll};
+use crate::utils::{burn_membership_nft, read_ban, write_ban};
+
+#[derive(Accounts)]
+pub struct ApplyPollOutcomeCtx<'info> {
+    // Anyone may crank a finalized poll once it is back on the base layer
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = poll.ended @ ErrorCode::PollNotEnded
+    )]
+    pub poll: Account<'info, Poll>,
+
+    #[account(
+        mut,
+        seeds = [
+            CHANNEL_SEED,
+            poll.channel_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub channel: Account<'info, Channel>,
+
+    #[account(
+        mut,
+        seeds = [
+            MEMBERSHIP_SEED,
+            poll.channel_id.to_le_bytes().as_ref(),
+            target_membership.member.as_ref()
+        ],
+        bump,
+        constraint = poll.target == Some(target_membership.member) @ ErrorCode::InvalidTarget
+    )]
+    pub target_membership: Account<'info, Membership>,
+
+    #[account(
+        mut,
+        seeds = [
+            MEMBER_STATS_SEED,
+            poll.channel_id.to_le_bytes().as_ref(),
+            target_membership.member.as_ref()
+        ],
+        bump
+    )]
+    pub target_member_stats: Account<'info, MemberStats>,
+
+    // Required to burn the NFT when kicking from a soulbound channel
+    #[account(
+        mut,
+        constraint = target_membership.nft_mint == Some(nft_mint.key()) @ ErrorCode::InvalidMembershipNft
+    )]
+    pub nft_mint: Option<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        constraint = target_membership.nft_mint == Some(nft_token_account.mint) @ ErrorCode::InvalidMembershipNft,
+        constraint = nft_token_account.owner == target_membership.member @ ErrorCode::InvalidMembershipNft
+    )]
+    pub nft_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    /// CHECK: Ban PDA for the target, required to kick - written by write_ban
+    #[account(
+        mut,
+        seeds = [
+            BAN_SEED,
+            poll.channel_id.to_le_bytes().as_ref(),
+            target_membership.member.as_ref()
+        ],
+        bump
+    )]
+    pub ban: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Option<Program<'info, System>>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump,
+        constraint = !config.is_paused(PAUSE_POLLS) @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+}
+
+pub fn apply_poll_outcome(ctx: Context<ApplyPollOutcomeCtx>) -> Result<()> {
+    let poll = &mut ctx.accounts.poll;
+    let channel = &mut ctx.accounts.channel;
+    let membership = &mut ctx.accounts.target_membership;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    require!(poll.is_moderation(), ErrorCode::InvalidPollType);
+    require!(poll.passed, ErrorCode::PollNotPassed);
+    require!(!poll.executed, ErrorCode::PollAlreadyExecuted);
+
+    match poll.poll_type {
+        POLL_TYPE_KICK => {
+            if channel.soulbound && membership.nft_mint.is_some() {
+                let (Some(nft_mint), Some(nft_token_account), Some(token_program)) = (
+                    &ctx.accounts.nft_mint,
+                    &ctx.accounts.nft_token_account,
+                    &ctx.accounts.token_program,
+                ) else {
+                    return Err(ErrorCode::MissingMembershipNft.into());
+                };
+
+                let channel_id_bytes = poll.channel_id.to_le_bytes();
+                let seeds = &[
+                    CHANNEL_SEED,
+                    channel_id_bytes.as_ref(),
+                    &[ctx.bumps.channel],
+                ];
+
+                burn_membership_nft(
+                    token_program.to_account_info(),
+                    nft_mint.to_account_info(),
+                    nft_token_account.to_account_info(),
+                    channel.to_account_info(),
+                    &[&seeds[..]],
+                )?;
+                membership.nft_mint = None;
+            }
+            // Keep the kicked member from paying straight back in
+            let (Some(ban), Some(system_program)) = (&ctx.accounts.ban, &ctx.accounts.system_program) else {
+                return Err(ErrorCode::MissingBanAccount.into());
+            };
+            let ban_expires_at = current_time
+                .checked_add(KICK_BAN_DURATION)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+            // A longer ban from a moderator stays in place
+            let longer_ban_exists = read_ban(ban)?
+                .is_some_and(|existing| existing.expires_at == 0 || existing.expires_at >= ban_expires_at);
+
+            let channel_id_bytes = poll.channel_id.to_le_bytes();
+            let ban_seeds = &[
+                BAN_SEED,
+                channel_id_bytes.as_ref(),
+                membership.member.as_ref(),
+                &[ctx.bumps.ban.ok_or(ErrorCode::MissingBanAccount)?],
+            ];
+            if !longer_ban_exists {
+                write_ban(
+                    &ban.to_account_info(),
+                    &ctx.accounts.payer.to_account_info(),
+                    &system_program.to_account_info(),
+                    &ban_seeds[..],
+                    poll.channel_id,
+                    membership.member,
+                    poll.key(),
+                    ban_expires_at,
+                )?;
+            }
+
+            if membership.joined {
+                membership.joined = false;
+                channel.member_count = channel.member_count
+                    .checked_sub(1)
+                    .ok_or(ErrorCode::ArithmeticOverflow)?;
+            }
+            msg!("User kicked from channel");
+        }
+        POLL_TYPE_MUTE => {
+            membership.muted_until = current_time
+                .checked_add(MUTE_DURATION)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+            let member_stats = &mut ctx.accounts.target_member_stats;
+            member_stats.times_muted = member_stats.times_muted.saturating_add(1);
+            msg!("User muted in channel");
+        }
+        _ => return Err(ErrorCode::InvalidPollType.into()),
+    }
+
+    poll.executed = true;
+
+    emit!(PollOutcomeApplied {
+        poll: poll.key(),
+        channel_id: poll.channel_id,
+        poll_type: poll.poll_type,
+        target: membership.member,
+        applied_at: current_time,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct PollOutcomeApplied {
+    pub poll: Pubkey,
+    pub channel_id: u64,
+    pub poll_type: u8,
+    pub target: Pubkey,
+    pub applied_at: i64,
+}
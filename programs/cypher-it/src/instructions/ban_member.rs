@@ -0,0 +1,160 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::constants::{BAN_SEED, CHANNEL_SEED, MEMBERSHIP_SEED, PAUSE_GENERAL, STATE_SEED};
+use crate::errors::ErrorCode;
+use crate::states::{Channel, Config, Membership};
+use crate::utils::{ban_expiry, burn_membership_nft, write_ban};
+
+// `duration` in seconds, 0 = permanent. Banning again replaces the previous expiry.
+pub fn ban_member(ctx: Context<BanMemberCtx>, channel_id: u64, wallet: Pubkey, duration: i64) -> Result<()> {
+    let channel = &mut ctx.accounts.channel;
+    let moderator = &ctx.accounts.moderator;
+
+    if !channel.is_moderator(&moderator.key()) {
+        return Err(ErrorCode::UnauthorizedModerator.into());
+    }
+
+    if channel.is_moderator(&wallet) {
+        return Err(ErrorCode::InvalidTarget.into());
+    }
+
+    let expires_at = ban_expiry(Clock::get()?.unix_timestamp, duration)?;
+
+    let channel_id_bytes = channel_id.to_le_bytes();
+    let seeds = &[
+        BAN_SEED,
+        channel_id_bytes.as_ref(),
+        wallet.as_ref(),
+        &[ctx.bumps.ban],
+    ];
+
+    write_ban(
+        &ctx.accounts.ban.to_account_info(),
+        &moderator.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &seeds[..],
+        channel_id,
+        wallet,
+        moderator.key(),
+        expires_at,
+    )?;
+
+    // A current member is removed too, since polls and messages only check `joined`
+    let membership_info = ctx.accounts.membership.to_account_info();
+    if membership_info.owner == &crate::ID && !membership_info.data_is_empty() {
+        let mut membership = Membership::try_deserialize(&mut &membership_info.try_borrow_data()?[..])?;
+        if membership.joined {
+            if channel.soulbound && membership.nft_mint.is_some() {
+                let (Some(nft_mint), Some(nft_token_account), Some(token_program)) = (
+                    &ctx.accounts.nft_mint,
+                    &ctx.accounts.nft_token_account,
+                    &ctx.accounts.token_program,
+                ) else {
+                    return Err(ErrorCode::MissingMembershipNft.into());
+                };
+                require!(
+                    membership.nft_mint == Some(nft_mint.key())
+                        && nft_token_account.mint == nft_mint.key()
+                        && nft_token_account.owner == wallet,
+                    ErrorCode::InvalidMembershipNft
+                );
+
+                let channel_seeds = &[
+                    CHANNEL_SEED,
+                    channel_id_bytes.as_ref(),
+                    &[ctx.bumps.channel],
+                ];
+                burn_membership_nft(
+                    token_program.to_account_info(),
+                    nft_mint.to_account_info(),
+                    nft_token_account.to_account_info(),
+                    channel.to_account_info(),
+                    &[&channel_seeds[..]],
+                )?;
+                membership.nft_mint = None;
+            }
+
+            membership.joined = false;
+            membership.try_serialize(&mut &mut membership_info.try_borrow_mut_data()?[..])?;
+            channel.member_count = channel.member_count
+                .checked_sub(1)
+                .ok_or(ErrorCode::ArithmeticOverflow)?;
+        }
+    }
+
+    emit!(MemberBanned {
+        channel_id,
+        wallet,
+        banned_by: moderator.key(),
+        expires_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(channel_id: u64, wallet: Pubkey)]
+pub struct BanMemberCtx<'info> {
+    #[account(
+        seeds = [STATE_SEED],
+        bump,
+        constraint = !config.is_paused(PAUSE_GENERAL) @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [
+            CHANNEL_SEED,
+            channel_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub channel: Account<'info, Channel>,
+
+    /// CHECK: The wallet's Membership PDA, uninitialized if it never joined - un-joined in the handler
+    #[account(
+        mut,
+        seeds = [
+            MEMBERSHIP_SEED,
+            channel_id.to_le_bytes().as_ref(),
+            wallet.as_ref()
+        ],
+        bump
+    )]
+    pub membership: UncheckedAccount<'info>,
+
+    // Required to burn the NFT when banning a member of a soulbound channel
+    #[account(mut)]
+    pub nft_mint: Option<Account<'info, Mint>>,
+
+    #[account(mut)]
+    pub nft_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    /// CHECK: Ban PDA, created on first ban and overwritten on later ones
+    #[account(
+        mut,
+        seeds = [
+            BAN_SEED,
+            channel_id.to_le_bytes().as_ref(),
+            wallet.as_ref()
+        ],
+        bump
+    )]
+    pub ban: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub moderator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct MemberBanned {
+    pub channel_id: u64,
+    pub wallet: Pubkey,
+    pub banned_by: Pubkey,
+    pub expires_at: i64,
+}
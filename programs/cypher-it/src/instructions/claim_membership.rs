@@ -0,0 +1,241 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::TokenAccount;
+use crate::constants::{
+    ANCHOR_DISCRIMINATOR_SIZE,
+    BAN_SEED,
+    BPS_PER_PERCENT,
+    CHANNEL_SEED,
+    FEE_SCHEDULE_SEED,
+    MEMBERSHIP_SEED,
+    MEMBERSHIP_VERSION,
+    MEMBER_STATS_SEED,
+    PAUSE_JOINS,
+    PLATFORM_BAN_SEED,
+    STATE_SEED,
+    TREASURY_SEED
+};
+use crate::errors::ErrorCode;
+use crate::states::{Channel, Config, FeeSchedule, MemberStats, Membership, PlatformTreasury};
+use crate::utils::{calculate_platform_fee, ensure_not_banned, safe_mul};
+
+pub fn claim_membership(ctx: Context<ClaimMembershipCtx>, channel_id: u64) -> Result<()> {
+    let fee_schedule = &ctx.accounts.fee_schedule;
+    let channel = &ctx.accounts.channel;
+    let previous_membership = &ctx.accounts.previous_membership;
+    let new_membership = &mut ctx.accounts.new_membership;
+    let member_stats = &mut ctx.accounts.member_stats;
+    let claimer = &ctx.accounts.claimer;
+
+    if !channel.transferable {
+        return Err(ErrorCode::MembershipNotTransferable.into());
+    }
+
+    let current_time = Clock::get()?.unix_timestamp;
+    ensure_not_banned(&ctx.accounts.platform_ban, current_time)?;
+    ensure_not_banned(&ctx.accounts.ban, current_time)?;
+
+    if !previous_membership.joined {
+        return Err(ErrorCode::NotChannelMember.into());
+    }
+
+    // Moving the seat would leave the open poll's outcome pointing at a closed account
+    if current_time <= previous_membership.targeted_until {
+        return Err(ErrorCode::TargetOfOpenPoll.into());
+    }
+
+    if new_membership.joined {
+        return Err(ErrorCode::AlreadyJoined.into());
+    }
+
+    // Fees are charged on the channel's listed cost, whatever the seat sold for
+    let royalty_bps = safe_mul(channel.transfer_royalty, BPS_PER_PERCENT)?;
+    let (creator_royalty, _) = calculate_platform_fee(channel.cost, royalty_bps)?;
+    let (platform_fee_amount, _) = calculate_platform_fee(channel.cost, fee_schedule.fee_bps_for(channel))?;
+
+    if creator_royalty > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: claimer.to_account_info(),
+                    to: ctx.accounts.creator.to_account_info(),
+                },
+            ),
+            creator_royalty,
+        )?;
+    }
+
+    if platform_fee_amount > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: claimer.to_account_info(),
+                    to: ctx.accounts.treasury.to_account_info(),
+                },
+            ),
+            platform_fee_amount,
+        )?;
+        ctx.accounts.treasury.record_fee(platform_fee_amount)?;
+    }
+
+    // Re-key the seat: the old Membership is closed and a new one keyed by the holder takes its place
+    new_membership.channel_id = channel_id;
+    new_membership.member = claimer.key();
+    new_membership.joined = true;
+    new_membership.joined_at = current_time;
+    new_membership.nft_mint = previous_membership.nft_mint;
+    // A mute follows the seat, and the claimer's own mute from an earlier stay still applies
+    new_membership.muted_until = previous_membership.muted_until.max(new_membership.muted_until);
+    new_membership.targeted_until = previous_membership.targeted_until.max(new_membership.targeted_until);
+    new_membership.version = MEMBERSHIP_VERSION;
+
+    // Polls require stats, so start tracking the claimer or keep counting for a returning one
+    if member_stats.member == Pubkey::default() {
+        member_stats.channel_id = channel_id;
+        member_stats.member = claimer.key();
+        member_stats.bump = ctx.bumps.member_stats;
+    }
+    member_stats.last_active_at = current_time;
+
+    emit!(MembershipClaimed {
+        channel_id,
+        previous_member: previous_membership.member,
+        new_member: claimer.key(),
+        nft_mint: ctx.accounts.nft_token_account.mint,
+        creator_royalty,
+        platform_fee_amount,
+        claimed_at: new_membership.joined_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(channel_id: u64)]
+pub struct ClaimMembershipCtx<'info> {
+    #[account(
+        seeds = [STATE_SEED],
+        bump,
+        constraint = !config.is_paused(PAUSE_JOINS) @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [
+            CHANNEL_SEED,
+            channel_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub channel: Account<'info, Channel>,
+
+    #[account(
+        mut,
+        seeds = [
+            MEMBERSHIP_SEED,
+            channel_id.to_le_bytes().as_ref(),
+            previous_member.key().as_ref()
+        ],
+        bump,
+        close = previous_member
+    )]
+    pub previous_membership: Account<'info, Membership>,
+
+    /// CHECK: Current seat holder - receives the closed membership's rent
+    #[account(
+        mut,
+        constraint = previous_member.key() != claimer.key() @ ErrorCode::CannotClaimOwnMembership
+    )]
+    pub previous_member: UncheckedAccount<'info>,
+
+    // Re-used if the claimer was a member before
+    #[account(
+        init_if_needed,
+        payer = claimer,
+        space = ANCHOR_DISCRIMINATOR_SIZE + Membership::INIT_SPACE,
+        seeds = [
+            MEMBERSHIP_SEED,
+            channel_id.to_le_bytes().as_ref(),
+            claimer.key().as_ref()
+        ],
+        bump
+    )]
+    pub new_membership: Account<'info, Membership>,
+
+    #[account(
+        init_if_needed,
+        payer = claimer,
+        space = ANCHOR_DISCRIMINATOR_SIZE + MemberStats::INIT_SPACE,
+        seeds = [
+            MEMBER_STATS_SEED,
+            channel_id.to_le_bytes().as_ref(),
+            claimer.key().as_ref()
+        ],
+        bump
+    )]
+    pub member_stats: Account<'info, MemberStats>,
+
+    // Proves the claimer now holds the seat's NFT
+    #[account(
+        constraint = previous_membership.nft_mint == Some(nft_token_account.mint) @ ErrorCode::InvalidMembershipNft,
+        constraint = nft_token_account.owner == claimer.key() @ ErrorCode::NotNftHolder,
+        constraint = nft_token_account.amount == 1 @ ErrorCode::NotNftHolder
+    )]
+    pub nft_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub claimer: Signer<'info>,
+
+    /// CHECK: Channel creator - validated by channel.creator
+    #[account(
+        mut,
+        constraint = creator.key() == channel.creator
+    )]
+    pub creator: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [FEE_SCHEDULE_SEED],
+        bump = fee_schedule.bump
+    )]
+    pub fee_schedule: Account<'info, FeeSchedule>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, PlatformTreasury>,
+
+    /// CHECK: Ban PDA for this wallet, usually uninitialized - checked in ensure_not_banned
+    #[account(
+        seeds = [
+            BAN_SEED,
+            channel_id.to_le_bytes().as_ref(),
+            claimer.key().as_ref()
+        ],
+        bump
+    )]
+    pub ban: UncheckedAccount<'info>,
+
+    /// CHECK: Platform ban PDA for this wallet - checked in ensure_not_banned
+    #[account(
+        seeds = [PLATFORM_BAN_SEED, claimer.key().as_ref()],
+        bump
+    )]
+    pub platform_ban: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct MembershipClaimed {
+    pub channel_id: u64,
+    pub previous_member: Pubkey,
+    pub new_member: Pubkey,
+    pub nft_mint: Pubkey,
+    pub creator_royalty: u64,
+    pub platform_fee_amount: u64,
+    pub claimed_at: i64,
+}
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use crate::constants::{ANCHOR_DISCRIMINATOR_SIZE, PAUSE_GENERAL, PROFILE_SEED, STATE_SEED, USERNAME_SEED};
+use crate::errors::ErrorCode;
+use crate::states::*;
+use crate::utils::{normalize_username, username_seed, validate_display_name};
+
+// Profiles created before handles existed have no Username PDA, so they can't be
+// renamed or closed until they claim one
+#[derive(Accounts)]
+#[instruction(display_name: String)]
+pub struct ClaimUsernameCtx<'info> {
+    #[account(
+        seeds = [STATE_SEED],
+        bump,
+        constraint = !config.is_paused(PAUSE_GENERAL) @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [PROFILE_SEED, owner.key().as_ref()],
+        bump,
+        has_one = owner
+    )]
+    pub profile: Account<'info, Profile>,
+    /// CHECK: Handle PDA for the profile's current name - must not already belong to this owner
+    #[account(
+        seeds = [USERNAME_SEED, username_seed(&profile.display_name).as_ref()],
+        bump
+    )]
+    pub current_username: UncheckedAccount<'info>,
+    // Fails to init if another profile already holds this handle
+    #[account(
+        init,
+        payer = owner,
+        space = ANCHOR_DISCRIMINATOR_SIZE + Username::INIT_SPACE,
+        seeds = [USERNAME_SEED, username_seed(&display_name).as_ref()],
+        bump
+    )]
+    pub username: Account<'info, Username>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn claim_username(ctx: Context<ClaimUsernameCtx>, display_name: String) -> Result<()> {
+    let profile = &mut ctx.accounts.profile;
+    let username = &mut ctx.accounts.username;
+    let owner = ctx.accounts.owner.key();
+
+    validate_display_name(&display_name)?;
+
+    // Claiming the current name just created it above; any other name is only allowed
+    // if the current one isn't already this profile's handle
+    if username_seed(&display_name) != username_seed(&profile.display_name) {
+        let current = ctx.accounts.current_username.to_account_info();
+        if current.owner == &crate::ID && !current.data_is_empty() {
+            let handle = Username::try_deserialize(&mut &current.try_borrow_data()?[..])?;
+            if handle.owner == owner {
+                return Err(ErrorCode::UsernameAlreadyClaimed.into());
+            }
+        }
+    }
+
+    username.owner = owner;
+    username.name = normalize_username(&display_name);
+    username.bump = ctx.bumps.username;
+
+    msg!("Handle {} claimed for {}", username.name, owner);
+    profile.display_name = display_name;
+
+    Ok(())
+}
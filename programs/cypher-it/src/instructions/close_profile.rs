@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::constants::{PAUSE_GENERAL, PROFILE_SEED, STATE_SEED, USERNAME_SEED};
+use crate::errors::ErrorCode;
+use crate::states::*;
+use crate::utils::username_seed;
+
+#[derive(Accounts)]
+pub struct CloseProfileCtx<'info> {
+    #[account(
+        seeds = [STATE_SEED],
+        bump,
+        constraint = !config.is_paused(PAUSE_GENERAL) @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [PROFILE_SEED, owner.key().as_ref()],
+        bump,
+        has_one = owner,
+        close = owner
+    )]
+    pub profile: Account<'info, Profile>,
+    #[account(
+        mut,
+        seeds = [USERNAME_SEED, username_seed(&profile.display_name).as_ref()],
+        bump = username.bump,
+        has_one = owner,
+        close = owner
+    )]
+    pub username: Account<'info, Username>,
+}
+
+pub fn close_profile(ctx: Context<CloseProfileCtx>) -> Result<()> {
+    msg!("Profile closed for {}", ctx.accounts.owner.key());
+    Ok(())
+}
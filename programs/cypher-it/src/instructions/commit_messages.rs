@@ -0,0 +1,137 @@
+use anchor_lang::prelude::*;
+use crate::constants::{
+    ANCHOR_DISCRIMINATOR_SIZE,
+    MAX_MESSAGE_BATCH_SIZE,
+    MEMBERSHIP_SEED,
+    MESSAGE_BATCH_SEED,
+    MESSAGE_LOG_SEED,
+    PAUSE_GENERAL,
+    STATE_SEED,
+};
+use crate::errors::ErrorCode;
+use crate::states::{Config, Membership, MessageBatch, MessageLog};
+
+pub fn commit_messages(
+    ctx: Context<CommitMessagesCtx>,
+    channel_id: u64,
+    merkle_root: [u8; 32],
+    start_sequence: u64,
+    end_sequence: u64,
+) -> Result<()> {
+    let message_log = &mut ctx.accounts.message_log;
+    let batch = &mut ctx.accounts.batch;
+    let submitter = &ctx.accounts.submitter;
+
+    // The relayer or any joined member may anchor a batch
+    let is_member = ctx
+        .accounts
+        .membership
+        .as_ref()
+        .is_some_and(|membership| membership.joined);
+    if message_log.relayer != submitter.key() && !is_member {
+        return Err(ErrorCode::UnauthorizedMessageCommit.into());
+    }
+
+    // Batches must be contiguous so every message has exactly one proof, and bounded
+    // so a member's bad range cannot push next_sequence out of the relayer's reach
+    if start_sequence != message_log.next_sequence
+        || end_sequence < start_sequence
+        || end_sequence - start_sequence >= MAX_MESSAGE_BATCH_SIZE
+    {
+        return Err(ErrorCode::InvalidSequenceRange.into());
+    }
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let index = message_log.batch_count;
+
+    batch.channel_id = channel_id;
+    batch.index = index;
+    batch.merkle_root = merkle_root;
+    batch.start_sequence = start_sequence;
+    batch.end_sequence = end_sequence;
+    batch.submitter = submitter.key();
+    batch.committed_at = current_time;
+    batch.bump = ctx.bumps.batch;
+
+    message_log.next_sequence = end_sequence
+        .checked_add(1)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    message_log.batch_count = index
+        .checked_add(1)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    message_log.last_root = merkle_root;
+    message_log.updated_at = current_time;
+
+    emit!(MessagesCommitted {
+        channel_id,
+        batch_index: index,
+        merkle_root,
+        start_sequence,
+        end_sequence,
+        submitter: submitter.key(),
+        committed_at: current_time,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(channel_id: u64)]
+pub struct CommitMessagesCtx<'info> {
+    #[account(
+        seeds = [STATE_SEED],
+        bump,
+        constraint = !config.is_paused(PAUSE_GENERAL) @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [
+            MESSAGE_LOG_SEED,
+            channel_id.to_le_bytes().as_ref()
+        ],
+        bump = message_log.bump
+    )]
+    pub message_log: Account<'info, MessageLog>,
+
+    #[account(
+        init,
+        payer = submitter,
+        space = ANCHOR_DISCRIMINATOR_SIZE + MessageBatch::INIT_SPACE,
+        seeds = [
+            MESSAGE_BATCH_SEED,
+            channel_id.to_le_bytes().as_ref(),
+            message_log.batch_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub batch: Account<'info, MessageBatch>,
+
+    // Required unless the submitter is the channel's relayer
+    #[account(
+        seeds = [
+            MEMBERSHIP_SEED,
+            channel_id.to_le_bytes().as_ref(),
+            submitter.key().as_ref()
+        ],
+        bump
+    )]
+    pub membership: Option<Account<'info, Membership>>,
+
+    #[account(mut)]
+    pub submitter: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct MessagesCommitted {
+    pub channel_id: u64,
+    pub batch_index: u64,
+    pub merkle_root: [u8; 32],
+    pub start_sequence: u64,
+    pub end_sequence: u64,
+    pub submitter: Pubkey,
+    pub committed_at: i64,
+}
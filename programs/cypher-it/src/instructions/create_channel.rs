@@ -1,17 +1,26 @@
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
-use anchor_spl::token::{self, initialize_mint, mint_to, Mint, MintTo, Token, TokenAccount};
+use anchor_spl::metadata::Metadata;
+use anchor_spl::token::{mint_to, Mint, MintTo, Token, TokenAccount};
 use crate::constants::{
     ANCHOR_DISCRIMINATOR_SIZE, 
     CHANNEL_SEED,
+    CHANNEL_VERSION,
     MEMBERSHIP_SEED,
+    MEMBERSHIP_VERSION,
+    MEMBER_STATS_SEED,
+    NFT_MINT_SEED,
+    PAUSE_JOINS,
+    PROTECTED_MODERATORS,
     STATE_SEED,
     MAX_CHANNEL_NAME_LENGTH,
     MAX_CHANNEL_DESCRIPTION_LENGTH,
-    MIN_CHANNEL_COST
+    MIN_CHANNEL_COST,
+    MAX_NFT_URI_LENGTH
 };
 use crate::errors::ErrorCode;
-use crate::states::{Channel, Config, Membership};
+use crate::states::{Channel, Config, MemberStats, Membership};
+use crate::utils::{create_membership_metadata, lock_membership_nft};
 
 pub fn create_channel(
     ctx: Context<CreateChannelCtx>,
@@ -20,10 +29,12 @@ pub fn create_channel(
     img_url: String,
     cost: u64,
     is_private: bool,
+    soulbound: bool,
 ) -> Result<()> {
     let config = &mut ctx.accounts.config;
     let channel = &mut ctx.accounts.channel;
     let membership = &mut ctx.accounts.membership;
+    let member_stats = &mut ctx.accounts.member_stats;
     let creator = &ctx.accounts.creator;
 
     // Validation
@@ -39,6 +50,11 @@ pub fn create_channel(
         return Err(ErrorCode::InvalidChannelCost.into());
     }
 
+    // Becomes the membership NFTs' metadata URI
+    if img_url.len() > MAX_NFT_URI_LENGTH {
+        return Err(ErrorCode::ImageUrlTooLong.into());
+    }
+
     // Increment total channels counter
     config.total_channels = config.total_channels
         .checked_add(1)
@@ -58,53 +74,39 @@ pub fn create_channel(
     channel.created_at = current_time;
     channel.updated_at = current_time;
     channel.img_url = img_url.clone();
+    channel.moderators = vec![];
+    channel.min_poll_reputation = 0;
+    channel.soulbound = soulbound;
+    channel.transferable = false;
+    channel.max_members = 0;
+    channel.transfer_royalty = 0;
+    channel.join_count = 1; // Creator holds membership #1
+    channel.fee_override_bps = None;
+    channel.version = CHANNEL_VERSION;
+    channel.protected_roles = PROTECTED_MODERATORS;
 
     // Initialize creator's membership
     membership.channel_id = channel_id;
     membership.member = creator.key();
     membership.joined = true;
     membership.joined_at = current_time;
+    membership.version = MEMBERSHIP_VERSION;
+
+    // Initialize creator's activity stats
+    member_stats.channel_id = channel_id;
+    member_stats.member = creator.key();
+    member_stats.last_active_at = current_time;
+    member_stats.bump = ctx.bumps.member_stats;
 
-    // Seeds for PDA signing
+    // The channel PDA signs as the NFT's authority
     let channel_id_bytes = channel_id.to_le_bytes();
-    let creator_key = creator.key();
     let seeds = &[
-        MEMBERSHIP_SEED,
+        CHANNEL_SEED,
         channel_id_bytes.as_ref(),
-        creator_key.as_ref(),
-        &[ctx.bumps.membership],
+        &[ctx.bumps.channel],
     ];
     let signer = &[&seeds[..]];
 
-    // Initialize the NFT mint (decimals = 0, authority = membership)
-    initialize_mint(
-        CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            token::InitializeMint {
-                mint: ctx.accounts.nft_mint.to_account_info(),
-                rent: ctx.accounts.rent.to_account_info(),
-            },
-        ),
-        0, // decimals
-        &membership.key(),
-        Some(&membership.key()),
-    )?;
-
-    // Create associated token account for the creator
-    anchor_spl::associated_token::create(
-        CpiContext::new(
-            ctx.accounts.associated_token_program.to_account_info(),
-            anchor_spl::associated_token::Create {
-                payer: creator.to_account_info(),
-                associated_token: ctx.accounts.nft_token_account.to_account_info(),
-                authority: creator.to_account_info(),
-                mint: ctx.accounts.nft_mint.to_account_info(),
-                system_program: ctx.accounts.system_program.to_account_info(),
-                token_program: ctx.accounts.token_program.to_account_info(),
-           },
-        ),
-    )?;
-
     // Mint 1 NFT to the creator's associated token account
     mint_to(
         CpiContext::new_with_signer(
@@ -112,13 +114,38 @@ pub fn create_channel(
             MintTo {
                 mint: ctx.accounts.nft_mint.to_account_info(),
                 to: ctx.accounts.nft_token_account.to_account_info(),
-                authority: membership.to_account_info(),
+                authority: channel.to_account_info(),
             },
             signer,
         ),
         1,
     )?;
 
+    create_membership_metadata(
+        ctx.accounts.token_metadata_program.to_account_info(),
+        ctx.accounts.nft_metadata.to_account_info(),
+        ctx.accounts.nft_mint.to_account_info(),
+        channel.to_account_info(),
+        creator.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.rent.to_account_info(),
+        &name,
+        &img_url,
+        channel.join_count,
+        signer,
+    )?;
+
+    if soulbound {
+        lock_membership_nft(
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.nft_mint.to_account_info(),
+            ctx.accounts.nft_token_account.to_account_info(),
+            creator.to_account_info(),
+            channel.to_account_info(),
+            signer,
+        )?;
+    }
+
     // Update membership with NFT mint address
     membership.nft_mint = Some(ctx.accounts.nft_mint.key());
 
@@ -129,6 +156,7 @@ pub fn create_channel(
         cost,
         creator: creator.key(),
         is_private,
+        soulbound,
         created_at: current_time,
         nft_mint: ctx.accounts.nft_mint.key(),
     });
@@ -137,12 +165,13 @@ pub fn create_channel(
 }
 
 #[derive(Accounts)]
-#[instruction(name: String, description: String, img_url: String, cost: u64, is_private: bool)]
+#[instruction(name: String, description: String, cost: u64, is_private: bool, img_url: String, soulbound: bool)]
 pub struct CreateChannelCtx<'info> {
     #[account(
         mut,
         seeds = [STATE_SEED],
-        bump
+        bump,
+        constraint = !config.is_paused(PAUSE_JOINS) @ ErrorCode::ProgramPaused
     )]
     pub config: Account<'info, Config>,
 
@@ -171,19 +200,59 @@ pub struct CreateChannelCtx<'info> {
     )]
     pub membership: Account<'info, Membership>,
 
-    /// Mint will be created via CPI
-    #[account(mut)]
+    #[account(
+        init,
+        payer = creator,
+        space = ANCHOR_DISCRIMINATOR_SIZE + MemberStats::INIT_SPACE,
+        seeds = [
+            MEMBER_STATS_SEED,
+            (config.total_channels + 1).to_le_bytes().as_ref(),
+            creator.key().as_ref()
+        ],
+        bump
+    )]
+    pub member_stats: Account<'info, MemberStats>,
+
+    // Keyed by join number too, since a mint can never be closed
+    #[account(
+        init,
+        payer = creator,
+        seeds = [
+            NFT_MINT_SEED,
+            (config.total_channels + 1).to_le_bytes().as_ref(),
+            creator.key().as_ref(),
+            1u64.to_le_bytes().as_ref()
+        ],
+        bump,
+        mint::decimals = 0,
+        mint::authority = channel,
+        mint::freeze_authority = channel
+    )]
     pub nft_mint: Account<'info, Mint>,
 
-    /// Token account will be created via CPI
-    #[account(mut)]
+    #[account(
+        init,
+        payer = creator,
+        associated_token::mint = nft_mint,
+        associated_token::authority = creator
+    )]
     pub nft_token_account: Account<'info, TokenAccount>,
 
+    /// CHECK: Metaplex metadata PDA for the NFT mint, created via CPI
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), nft_mint.key().as_ref()],
+        bump,
+        seeds::program = token_metadata_program.key()
+    )]
+    pub nft_metadata: UncheckedAccount<'info>,
+
     #[account(mut)]
     pub creator: Signer<'info>,
 
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_metadata_program: Program<'info, Metadata>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
 }
@@ -196,6 +265,7 @@ pub struct ChannelCreated {
     pub cost: u64,
     pub creator: Pubkey,
     pub is_private: bool,
+    pub soulbound: bool,
     pub created_at: i64,
     pub nft_mint: Pubkey,
 }
\ No newline at end of file
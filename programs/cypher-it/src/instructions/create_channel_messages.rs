@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::constants::{ANCHOR_DISCRIMINATOR_SIZE, CHANNEL_MESSAGES_SEED, CHANNEL_SEED, PAUSE_GENERAL, STATE_SEED};
+use crate::errors::ErrorCode;
+use crate::states::{Channel, ChannelMessages, Config};
+
+pub fn create_channel_messages(ctx: Context<CreateChannelMessagesCtx>, channel_id: u64) -> Result<()> {
+    let channel_messages = &mut ctx.accounts.channel_messages;
+
+    channel_messages.channel_id = channel_id;
+    channel_messages.total_posted = 0;
+    channel_messages.messages = vec![];
+    channel_messages.bump = ctx.bumps.channel_messages;
+
+    msg!("On-chain messages enabled for channel: {}", channel_id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(channel_id: u64)]
+pub struct CreateChannelMessagesCtx<'info> {
+    #[account(
+        seeds = [STATE_SEED],
+        bump,
+        constraint = !config.is_paused(PAUSE_GENERAL) @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [
+            CHANNEL_SEED,
+            channel_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        constraint = channel.creator == creator.key() @ ErrorCode::UnauthorizedChannelUpdate
+    )]
+    pub channel: Account<'info, Channel>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = ANCHOR_DISCRIMINATOR_SIZE + ChannelMessages::INIT_SPACE,
+        seeds = [
+            CHANNEL_MESSAGES_SEED,
+            channel_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub channel_messages: Account<'info, ChannelMessages>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
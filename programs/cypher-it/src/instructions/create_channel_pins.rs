@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::constants::{ANCHOR_DISCRIMINATOR_SIZE, CHANNEL_PINS_SEED, CHANNEL_SEED, PAUSE_GENERAL, STATE_SEED};
+use crate::errors::ErrorCode;
+use crate::states::{Channel, ChannelPins, Config};
+
+pub fn create_channel_pins(ctx: Context<CreateChannelPinsCtx>, channel_id: u64) -> Result<()> {
+    let channel_pins = &mut ctx.accounts.channel_pins;
+
+    channel_pins.channel_id = channel_id;
+    channel_pins.pins = vec![];
+    channel_pins.bump = ctx.bumps.channel_pins;
+
+    msg!("Pins enabled for channel: {}", channel_id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(channel_id: u64)]
+pub struct CreateChannelPinsCtx<'info> {
+    #[account(
+        seeds = [STATE_SEED],
+        bump,
+        constraint = !config.is_paused(PAUSE_GENERAL) @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [
+            CHANNEL_SEED,
+            channel_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        constraint = channel.creator == creator.key() @ ErrorCode::UnauthorizedChannelUpdate
+    )]
+    pub channel: Account<'info, Channel>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = ANCHOR_DISCRIMINATOR_SIZE + ChannelPins::INIT_SPACE,
+        seeds = [
+            CHANNEL_PINS_SEED,
+            channel_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub channel_pins: Account<'info, ChannelPins>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use crate::constants::{
+    ANCHOR_DISCRIMINATOR_SIZE,
+    BPS_PER_PERCENT,
+    FEE_SCHEDULE_SEED,
+    STATE_SEED
+};
+use crate::errors::ErrorCode;
+use crate::states::{Config, FeeSchedule};
+use crate::utils::safe_mul;
+
+// Migrates deployments from the legacy percentage fee in Config to basis points
+pub fn create_fee_schedule(ctx: Context<CreateFeeScheduleCtx>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let fee_schedule = &mut ctx.accounts.fee_schedule;
+
+    fee_schedule.base_fee_bps = safe_mul(config.platform_fee, BPS_PER_PERCENT)?;
+    fee_schedule.tiers = Vec::new();
+    fee_schedule.bump = ctx.bumps.fee_schedule;
+
+    emit!(FeeScheduleCreated {
+        owner: ctx.accounts.owner.key(),
+        base_fee_bps: fee_schedule.base_fee_bps,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CreateFeeScheduleCtx<'info> {
+    #[account(
+        seeds = [STATE_SEED],
+        bump,
+        constraint = config.owner == owner.key() @ ErrorCode::UnauthorizedOwner
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = ANCHOR_DISCRIMINATOR_SIZE + FeeSchedule::INIT_SPACE,
+        seeds = [FEE_SCHEDULE_SEED],
+        bump
+    )]
+    pub fee_schedule: Account<'info, FeeSchedule>,
+
+    pub owner: Signer<'info>,
+
+    // Separate from owner so a multisig PDA never has to fund rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct FeeScheduleCreated {
+    pub owner: Pubkey,
+    pub base_fee_bps: u64,
+}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use crate::constants::{ANCHOR_DISCRIMINATOR_SIZE, CHANNEL_SEED, MESSAGE_LOG_SEED, PAUSE_GENERAL, STATE_SEED};
+use crate::errors::ErrorCode;
+use crate::states::{Channel, Config, MessageLog};
+
+pub fn create_message_log(
+    ctx: Context<CreateMessageLogCtx>,
+    channel_id: u64,
+    relayer: Pubkey,
+) -> Result<()> {
+    let message_log = &mut ctx.accounts.message_log;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    message_log.channel_id = channel_id;
+    message_log.relayer = relayer;
+    message_log.next_sequence = 0;
+    message_log.batch_count = 0;
+    message_log.last_root = [0; 32];
+    message_log.updated_at = current_time;
+    message_log.bump = ctx.bumps.message_log;
+
+    emit!(MessageLogCreated {
+        channel_id,
+        relayer,
+        created_at: current_time,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(channel_id: u64)]
+pub struct CreateMessageLogCtx<'info> {
+    #[account(
+        seeds = [STATE_SEED],
+        bump,
+        constraint = !config.is_paused(PAUSE_GENERAL) @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [
+            CHANNEL_SEED,
+            channel_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        constraint = channel.creator == creator.key() @ ErrorCode::UnauthorizedChannelUpdate
+    )]
+    pub channel: Account<'info, Channel>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = ANCHOR_DISCRIMINATOR_SIZE + MessageLog::INIT_SPACE,
+        seeds = [
+            MESSAGE_LOG_SEED,
+            channel_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub message_log: Account<'info, MessageLog>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct MessageLogCreated {
+    pub channel_id: u64,
+    pub relayer: Pubkey,
+    pub created_at: i64,
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::constants::{ANCHOR_DISCRIMINATOR_SIZE, MEMBERSHIP_SEED, MESSAGE_REACTIONS_SEED, PAUSE_GENERAL, STATE_SEED};
+use crate::errors::ErrorCode;
+use crate::states::{Config, Membership, MessageReactions};
+
+pub fn create_message_reactions(
+    ctx: Context<CreateMessageReactionsCtx>,
+    channel_id: u64,
+    message_hash: [u8; 32],
+) -> Result<()> {
+    let message_reactions = &mut ctx.accounts.message_reactions;
+
+    message_reactions.channel_id = channel_id;
+    message_reactions.message_hash = message_hash;
+    message_reactions.reactions = vec![];
+    message_reactions.reactors = vec![];
+    message_reactions.bump = ctx.bumps.message_reactions;
+
+    msg!("Reactions enabled for message in channel: {}", channel_id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(channel_id: u64, message_hash: [u8; 32])]
+pub struct CreateMessageReactionsCtx<'info> {
+    #[account(
+        seeds = [STATE_SEED],
+        bump,
+        constraint = !config.is_paused(PAUSE_GENERAL) @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [
+            MEMBERSHIP_SEED,
+            channel_id.to_le_bytes().as_ref(),
+            member.key().as_ref()
+        ],
+        bump,
+        constraint = membership.joined @ ErrorCode::NotChannelMember
+    )]
+    pub membership: Account<'info, Membership>,
+
+    #[account(
+        init,
+        payer = member,
+        space = ANCHOR_DISCRIMINATOR_SIZE + MessageReactions::INIT_SPACE,
+        seeds = [
+            MESSAGE_REACTIONS_SEED,
+            channel_id.to_le_bytes().as_ref(),
+            message_hash.as_ref()
+        ],
+        bump
+    )]
+    pub message_reactions: Account<'info, MessageReactions>,
+
+    #[account(mut)]
+    pub member: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -1,7 +1,10 @@
 // instructions/create_poll.rs
 use anchor_lang::prelude::*;
-use crate::states::{Channel, Membership, Poll, Config, Profile};
+use anchor_lang::system_program;
+use crate::constants::{MEMBER_STATS_SEED, PAUSE_POLLS, POLL_TYPE_KICK, POLL_TYPE_MUTE, POLL_VERSION};
+use crate::states::{Channel, Membership, MemberStats, Poll, Config, Profile};
 use crate::errors::ErrorCode;
+use crate::utils::effective_reputation;
 
 #[derive(Accounts)]
 #[instruction(channel_id: u64, poll_type: u8)]
@@ -17,7 +20,8 @@ pub struct CreatePollCtx<'info> {
     pub channel: Account<'info, Channel>,
 
     #[account(
-        seeds = [b"membership", creator.key().as_ref(), channel.key().as_ref()],
+        mut,
+        seeds = [b"membership", channel_id.to_le_bytes().as_ref(), creator.key().as_ref()],
         bump 
     )]
     pub membership: Account<'info, Membership>,
@@ -37,12 +41,24 @@ pub struct CreatePollCtx<'info> {
     )]
     pub poll: Account<'info, Poll>,
 
+    #[account(
+        mut,
+        seeds = [MEMBER_STATS_SEED, channel_id.to_le_bytes().as_ref(), creator.key().as_ref()],
+        bump = member_stats.bump
+    )]
+    pub member_stats: Account<'info, MemberStats>,
+
     // Optional target user for kick/mute polls
     pub target_profile: Option<Account<'info, Profile>>,
 
+    // Required with target_profile, proves the target belongs to this channel
+    #[account(mut)]
+    pub target_membership: Option<Account<'info, Membership>>,
+
     #[account(
-        seeds = [b"config"],
-        bump 
+        seeds = [b"state"],
+        bump,
+        constraint = !config.is_paused(PAUSE_POLLS) @ ErrorCode::ProgramPaused
     )]
     pub config: Account<'info, Config>,
 
@@ -63,10 +79,86 @@ pub fn create_poll(
 
     // Verify membership
     require!(
-        ctx.accounts.membership.channel_id == channel_id,
+        ctx.accounts.membership.channel_id == channel_id && ctx.accounts.membership.joined,
         ErrorCode::NotMember
     );
 
+    // Channels can require a reputation floor to curb spam polls
+    require!(
+        effective_reputation(
+            &ctx.accounts.profile,
+            &ctx.accounts.membership,
+            clock.unix_timestamp
+        ) >= channel.min_poll_reputation,
+        ErrorCode::InsufficientReputation
+    );
+
+    // Rate limits against poll spam
+    let (min_duration, max_duration) = channel.poll_duration_bounds();
+    require!(
+        duration >= min_duration && duration <= max_duration,
+        ErrorCode::InvalidPollDuration
+    );
+    require!(
+        channel.open_polls < channel.open_poll_limit(),
+        ErrorCode::TooManyOpenPolls
+    );
+    let membership = &mut ctx.accounts.membership;
+    require!(
+        channel.poll_cooldown == 0
+            || membership.last_poll_at == 0
+            || clock.unix_timestamp >= membership.last_poll_at.saturating_add(channel.poll_cooldown),
+        ErrorCode::PollCooldownActive
+    );
+    membership.last_poll_at = clock.unix_timestamp;
+
+    // Held by the poll until settle_poll_deposit
+    if channel.poll_deposit > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.creator.to_account_info(),
+                    to: poll.to_account_info(),
+                },
+            ),
+            channel.poll_deposit,
+        )?;
+    }
+    poll.deposit = channel.poll_deposit;
+    poll.deposit_settled = false;
+
+    // Moderation polls need a real, unprotected member of this channel as target
+    if poll_type == POLL_TYPE_KICK || poll_type == POLL_TYPE_MUTE {
+        let (Some(target), Some(target_membership)) = (
+            &ctx.accounts.target_profile,
+            &mut ctx.accounts.target_membership,
+        ) else {
+            return Err(ErrorCode::MissingTarget.into());
+        };
+
+        require!(
+            target_membership.channel_id == channel_id
+                && target_membership.member == target.owner
+                && target_membership.joined,
+            ErrorCode::TargetNotMember
+        );
+        require!(target.owner != ctx.accounts.creator.key(), ErrorCode::CannotTargetSelf);
+        require!(!channel.is_protected(&target.owner), ErrorCode::ProtectedTarget);
+        require!(
+            clock.unix_timestamp > target_membership.targeted_until,
+            ErrorCode::DuplicateTargetPoll
+        );
+
+        target_membership.targeted_until = clock.unix_timestamp
+            .checked_add(duration)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+    }
+
+    // Eligibility is fixed at creation so seats bought mid-poll can't swing it
+    let member_count_snapshot = channel.member_count;
+    poll.member_count_snapshot = member_count_snapshot;
+
     // Validate poll type and setup accordingly
     match poll_type {
         0 => {
@@ -86,11 +178,15 @@ pub fn create_poll(
             poll.total_votes = 0;
             poll.start_time = clock.unix_timestamp;
             poll.end_time = clock.unix_timestamp + duration;
-            poll.required_votes = (channel.member_count / 2) as u64 + 1; // Simple majority
+            poll.required_votes = member_count_snapshot / 2 + 1; // Simple majority
             poll.target = Some(target.owner);
             poll.voted = vec![];
             poll.ended = false;
+            poll.passed = false;
+            poll.executed = false;
+            poll.reputation_settled = false;
             poll.bump = ctx.bumps.poll;
+            poll.version = POLL_VERSION;
         }
         1 => {
             // Mute user poll
@@ -109,11 +205,15 @@ pub fn create_poll(
             poll.total_votes = 0;
             poll.start_time = clock.unix_timestamp;
             poll.end_time = clock.unix_timestamp + duration;
-            poll.required_votes = (channel.member_count / 2) as u64 + 1;
+            poll.required_votes = member_count_snapshot / 2 + 1;
             poll.target = Some(target.owner);
             poll.voted = vec![];
             poll.ended = false;
+            poll.passed = false;
+            poll.executed = false;
+            poll.reputation_settled = false;
             poll.bump = ctx.bumps.poll;
+            poll.version = POLL_VERSION;
         }
         2 => {
             // Normal poll
@@ -137,15 +237,24 @@ pub fn create_poll(
             poll.target = None;
             poll.voted = vec![];
             poll.ended = false;
+            poll.passed = false;
+            poll.executed = false;
+            poll.reputation_settled = false;
             poll.bump = ctx.bumps.poll;
+            poll.version = POLL_VERSION;
         }
         _ => return Err(ErrorCode::InvalidPollType.into()),
     }
 
     // Increment channel poll count
     channel.poll_count = channel.poll_count.checked_add(1).unwrap();
+    channel.open_polls = channel.open_polls.saturating_add(1);
     channel.updated_at = clock.unix_timestamp;
 
+    let member_stats = &mut ctx.accounts.member_stats;
+    member_stats.polls_created = member_stats.polls_created.saturating_add(1);
+    member_stats.touch(clock.unix_timestamp);
+
     msg!("Poll created successfully for channel: {}", channel_id);
     Ok(())
 }
\ No newline at end of file
@@ -1,18 +1,44 @@
 use anchor_lang::prelude::*;
+use crate::constants::{ANCHOR_DISCRIMINATOR_SIZE, PAUSE_GENERAL, PROFILE_SEED, PROFILE_VERSION, STATE_SEED, USERNAME_SEED};
+use crate::errors::ErrorCode;
 use crate::states::*;
+use crate::utils::{
+    normalize_username,
+    username_seed,
+    validate_avatar_uri,
+    validate_bio,
+    validate_display_name,
+};
 
 #[derive(Accounts)]
+#[instruction(display_name: String)]
 pub struct CreateProfileCtx<'info> {
+    #[account(
+        seeds = [STATE_SEED],
+        bump,
+        constraint = !config.is_paused(PAUSE_GENERAL) @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
     #[account(mut)]
     pub payer: Signer<'info>,
     #[account(
         init,
         payer = payer,
-        space = 8 + Profile::MAX_SIZE as usize,
-        seeds = [b"profile", payer.key().as_ref()],
+        space = ANCHOR_DISCRIMINATOR_SIZE + Profile::INIT_SPACE,
+        seeds = [PROFILE_SEED, payer.key().as_ref()],
         bump
     )]
     pub profile: Account<'info, Profile>,
+    // Fails to init if another profile already holds this handle
+    #[account(
+        init,
+        payer = payer,
+        space = ANCHOR_DISCRIMINATOR_SIZE + Username::INIT_SPACE,
+        seeds = [USERNAME_SEED, username_seed(&display_name).as_ref()],
+        bump
+    )]
+    pub username: Account<'info, Username>,
     pub system_program: Program<'info, System>,
 }
 
@@ -23,12 +49,23 @@ pub fn create_profile(
     avatar_uri: String,
 ) -> Result<()> {
     let profile = &mut ctx.accounts.profile;
+    let username = &mut ctx.accounts.username;
+
+    validate_display_name(&display_name)?;
+    validate_bio(&bio)?;
+    validate_avatar_uri(&avatar_uri)?;
+
+    username.owner = ctx.accounts.payer.key();
+    username.name = normalize_username(&display_name);
+    username.bump = ctx.bumps.username;
 
     profile.owner = ctx.accounts.payer.key();
     profile.display_name = display_name;
     profile.bio = bio;
     profile.avatar_uri = avatar_uri;
     profile.created_at = Clock::get()?.unix_timestamp;
+    profile.reputation = 0;
+    profile.version = PROFILE_VERSION;
 
     Ok(())
 }
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::constants::{ANCHOR_DISCRIMINATOR_SIZE, STATE_SEED, TREASURY_SEED};
+use crate::errors::ErrorCode;
+use crate::states::{Config, PlatformTreasury};
+
+// For programs initialized before the treasury existed
+pub fn create_treasury(ctx: Context<CreateTreasuryCtx>) -> Result<()> {
+    let treasury = &mut ctx.accounts.treasury;
+
+    treasury.total_collected = 0;
+    treasury.total_withdrawn = 0;
+    treasury.bump = ctx.bumps.treasury;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CreateTreasuryCtx<'info> {
+    #[account(
+        seeds = [STATE_SEED],
+        bump,
+        constraint = config.owner == owner.key() @ ErrorCode::UnauthorizedOwner
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = ANCHOR_DISCRIMINATOR_SIZE + PlatformTreasury::INIT_SPACE,
+        seeds = [TREASURY_SEED],
+        bump
+    )]
+    pub treasury: Account<'info, PlatformTreasury>,
+
+    pub owner: Signer<'info>,
+
+    // Separate from owner so a multisig PDA never has to fund rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use ephemeral_rollups_sdk::anchor::delegate;
+use ephemeral_rollups_sdk::cpi::DelegateConfig;
+use crate::constants::{CHANNEL_MESSAGES_SEED, CHANNEL_SEED, PAUSE_GENERAL, STATE_SEED};
+use crate::errors::ErrorCode;
+use crate::states::{Channel, Config};
+
+#[delegate]
+#[derive(Accounts)]
+#[instruction(channel_id: u64)]
+pub struct DelegateChannelMessagesCtx<'info> {
+    #[account(
+        seeds = [STATE_SEED],
+        bump,
+        constraint = !config.is_paused(PAUSE_GENERAL) @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [CHANNEL_SEED, channel_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = channel.creator == payer.key() @ ErrorCode::UnauthorizedDelegation
+    )]
+    pub channel: Account<'info, Channel>,
+
+    /// CHECK: The channel messages PDA to delegate
+    #[account(
+        mut, del,
+        seeds = [CHANNEL_MESSAGES_SEED, channel_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub channel_messages: AccountInfo<'info>,
+}
+
+pub fn delegate_channel_messages(ctx: Context<DelegateChannelMessagesCtx>, channel_id: u64) -> Result<()> {
+    ctx.accounts.delegate_channel_messages(
+        &ctx.accounts.payer,
+        &[CHANNEL_MESSAGES_SEED, channel_id.to_le_bytes().as_ref()],
+        DelegateConfig {
+            commit_frequency_ms: 30_000,
+            validator: Some(
+                "MAS1Dt9qreoRMQ14YQuhg8UTZMMzDdKhmkZMECCzk57"
+                    .parse::<Pubkey>()
+                    .unwrap(),
+            ),
+        },
+    )?;
+    msg!("Channel messages delegated for channel: {}", channel_id);
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use ephemeral_rollups_sdk::anchor::delegate;
+use ephemeral_rollups_sdk::cpi::DelegateConfig;
+use crate::constants::{MEMBERSHIP_SEED, MEMBER_STATS_SEED, PAUSE_POLLS, STATE_SEED};
+use crate::errors::ErrorCode;
+use crate::states::{Config, Membership};
+
+// Lets a member vote in the rollup, where only delegated accounts are writable
+#[delegate]
+#[derive(Accounts)]
+#[instruction(channel_id: u64)]
+pub struct DelegateMemberStatsCtx<'info> {
+    #[account(
+        seeds = [STATE_SEED],
+        bump,
+        constraint = !config.is_paused(PAUSE_POLLS) @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            MEMBERSHIP_SEED,
+            channel_id.to_le_bytes().as_ref(),
+            payer.key().as_ref()
+        ],
+        bump,
+        constraint = membership.joined @ ErrorCode::NotChannelMember
+    )]
+    pub membership: Account<'info, Membership>,
+
+    /// CHECK: The member stats PDA to delegate
+    #[account(
+        mut, del,
+        seeds = [
+            MEMBER_STATS_SEED,
+            channel_id.to_le_bytes().as_ref(),
+            payer.key().as_ref()
+        ],
+        bump
+    )]
+    pub member_stats: AccountInfo<'info>,
+}
+
+pub fn delegate_member_stats(ctx: Context<DelegateMemberStatsCtx>, channel_id: u64) -> Result<()> {
+    // apply_poll_outcome needs the target's stats on the base layer
+    require!(
+        Clock::get()?.unix_timestamp > ctx.accounts.membership.targeted_until,
+        ErrorCode::TargetOfOpenPoll
+    );
+
+    let payer_key = ctx.accounts.payer.key();
+    ctx.accounts.delegate_member_stats(
+        &ctx.accounts.payer,
+        &[
+            MEMBER_STATS_SEED,
+            channel_id.to_le_bytes().as_ref(),
+            payer_key.as_ref(),
+        ],
+        DelegateConfig {
+            commit_frequency_ms: 30_000,
+            validator: Some(
+                "MAS1Dt9qreoRMQ14YQuhg8UTZMMzDdKhmkZMECCzk57"
+                    .parse::<Pubkey>()
+                    .unwrap(),
+            ),
+        },
+    )?;
+    msg!("Member stats delegated for channel: {}", channel_id);
+    Ok(())
+}
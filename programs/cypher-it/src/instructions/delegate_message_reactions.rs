@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use ephemeral_rollups_sdk::anchor::delegate;
+use ephemeral_rollups_sdk::cpi::DelegateConfig;
+use crate::constants::{MEMBERSHIP_SEED, MESSAGE_REACTIONS_SEED, PAUSE_GENERAL, STATE_SEED};
+use crate::errors::ErrorCode;
+use crate::states::{Config, Membership};
+
+#[delegate]
+#[derive(Accounts)]
+#[instruction(channel_id: u64, message_hash: [u8; 32])]
+pub struct DelegateMessageReactionsCtx<'info> {
+    #[account(
+        seeds = [STATE_SEED],
+        bump,
+        constraint = !config.is_paused(PAUSE_GENERAL) @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [MEMBERSHIP_SEED, channel_id.to_le_bytes().as_ref(), payer.key().as_ref()],
+        bump,
+        constraint = membership.joined @ ErrorCode::NotChannelMember
+    )]
+    pub membership: Account<'info, Membership>,
+
+    /// CHECK: The message reactions PDA to delegate
+    #[account(
+        mut, del,
+        seeds = [MESSAGE_REACTIONS_SEED, channel_id.to_le_bytes().as_ref(), message_hash.as_ref()],
+        bump
+    )]
+    pub message_reactions: AccountInfo<'info>,
+}
+
+pub fn delegate_message_reactions(
+    ctx: Context<DelegateMessageReactionsCtx>,
+    channel_id: u64,
+    message_hash: [u8; 32],
+) -> Result<()> {
+    ctx.accounts.delegate_message_reactions(
+        &ctx.accounts.payer,
+        &[
+            MESSAGE_REACTIONS_SEED,
+            channel_id.to_le_bytes().as_ref(),
+            message_hash.as_ref(),
+        ],
+        DelegateConfig {
+            commit_frequency_ms: 30_000,
+            validator: Some(
+                "MAS1Dt9qreoRMQ14YQuhg8UTZMMzDdKhmkZMECCzk57"
+                    .parse::<Pubkey>()
+                    .unwrap(),
+            ),
+        },
+    )?;
+    msg!("Message reactions delegated for channel: {}", channel_id);
+    Ok(())
+}
@@ -1,15 +1,37 @@
 use anchor_lang::prelude::*;
 use ephemeral_rollups_sdk::anchor::delegate;
 use ephemeral_rollups_sdk::cpi::DelegateConfig;
+use crate::constants::{CHANNEL_SEED, PAUSE_POLLS, STATE_SEED};
+use crate::errors::ErrorCode;
+use crate::states::{Channel, Config, Poll};
 
 #[delegate]
 #[derive(Accounts)]
+#[instruction(channel_id: u64, poll_index: u64)]
 pub struct DelegatePollCtx<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [CHANNEL_SEED, channel_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub channel: Account<'info, Channel>,
+
     /// CHECK: The poll PDA to delegate
-    #[account(mut, del)]
+    #[account(
+        mut, del,
+        seeds = [b"poll", channel.key().as_ref(), poll_index.to_le_bytes().as_ref()],
+        bump
+    )]
     pub poll: AccountInfo<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump,
+        constraint = !config.is_paused(PAUSE_POLLS) @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
 }
 
 pub fn delegate_poll(
@@ -17,11 +39,25 @@ pub fn delegate_poll(
     channel_id: u64,
     poll_index: u64,
 ) -> Result<()> {
+    let payer = ctx.accounts.payer.key();
+    let channel = &ctx.accounts.channel;
+    let poll = Poll::try_deserialize(&mut &ctx.accounts.poll.try_borrow_data()?[..])?;
+
+    require!(poll.channel_id == channel_id, ErrorCode::InvalidChannel);
+    require!(!poll.ended, ErrorCode::PollAlreadyEnded);
+
+    // Only the poll creator or the channel creator can move a poll into the rollup
+    require!(
+        poll.creator == payer || channel.creator == payer,
+        ErrorCode::UnauthorizedDelegation
+    );
+
+    let channel_key = channel.key();
     ctx.accounts.delegate_poll(
         &ctx.accounts.payer,
         &[
             b"poll",
-            channel_id.to_le_bytes().as_ref(),
+            channel_key.as_ref(),
             poll_index.to_le_bytes().as_ref(),
         ],
         DelegateConfig {
@@ -31,9 +67,8 @@ pub fn delegate_poll(
                     .parse::<Pubkey>()
                     .unwrap(),
             ),
-            ..Default::default()
         },
     )?;
     msg!("Poll delegated for channel: {}, index: {}", channel_id, poll_index);
     Ok(())
-}
\ No newline at end of file
+}
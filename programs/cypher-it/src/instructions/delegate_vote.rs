@@ -0,0 +1,125 @@
+use anchor_lang::prelude::*;
+use crate::constants::{
+    ANCHOR_DISCRIMINATOR_SIZE,
+    MEMBERSHIP_SEED,
+    PAUSE_POLLS,
+    STATE_SEED,
+    VOTE_DELEGATION_SEED
+};
+use crate::errors::ErrorCode;
+use crate::states::{Config, Membership, VoteDelegation};
+
+// One hop only: weight delegated to someone who has delegated themselves would never be counted
+pub fn delegate_vote(ctx: Context<DelegateVoteCtx>, channel_id: u64, delegate: Pubkey) -> Result<()> {
+    let delegation = &mut ctx.accounts.delegation;
+    let delegate_membership = &mut ctx.accounts.delegate_membership;
+    let delegator = &ctx.accounts.delegator;
+
+    if delegate == delegator.key() {
+        return Err(ErrorCode::CannotDelegateToSelf.into());
+    }
+
+    if !ctx.accounts.delegator_membership.joined {
+        return Err(ErrorCode::NotChannelMember.into());
+    }
+
+    if !delegate_membership.joined {
+        return Err(ErrorCode::DelegateNotMember.into());
+    }
+
+    // Checked from both ends, so a chain can't form in either order
+    if ctx.accounts.delegator_membership.delegations_received > 0 {
+        return Err(ErrorCode::DelegatorHoldsDelegations.into());
+    }
+
+    let delegate_delegation = &ctx.accounts.delegate_delegation;
+    if delegate_delegation.owner == &crate::ID && !delegate_delegation.data_is_empty() {
+        return Err(ErrorCode::DelegationChain.into());
+    }
+
+    delegation.channel_id = channel_id;
+    delegation.delegator = delegator.key();
+    delegation.delegate = delegate;
+    delegation.created_at = Clock::get()?.unix_timestamp;
+    delegation.bump = ctx.bumps.delegation;
+
+    delegate_membership.delegations_received = delegate_membership.delegations_received
+        .checked_add(1)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    emit!(VoteDelegated {
+        channel_id,
+        delegator: delegator.key(),
+        delegate,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(channel_id: u64, delegate: Pubkey)]
+pub struct DelegateVoteCtx<'info> {
+    #[account(
+        init,
+        payer = delegator,
+        space = ANCHOR_DISCRIMINATOR_SIZE + VoteDelegation::INIT_SPACE,
+        seeds = [
+            VOTE_DELEGATION_SEED,
+            channel_id.to_le_bytes().as_ref(),
+            delegator.key().as_ref()
+        ],
+        bump
+    )]
+    pub delegation: Account<'info, VoteDelegation>,
+
+    #[account(
+        seeds = [
+            MEMBERSHIP_SEED,
+            channel_id.to_le_bytes().as_ref(),
+            delegator.key().as_ref()
+        ],
+        bump
+    )]
+    pub delegator_membership: Account<'info, Membership>,
+
+    #[account(
+        mut,
+        seeds = [
+            MEMBERSHIP_SEED,
+            channel_id.to_le_bytes().as_ref(),
+            delegate.as_ref()
+        ],
+        bump
+    )]
+    pub delegate_membership: Account<'info, Membership>,
+
+    /// CHECK: The delegate's own delegation PDA, usually uninitialized - must be empty
+    #[account(
+        seeds = [
+            VOTE_DELEGATION_SEED,
+            channel_id.to_le_bytes().as_ref(),
+            delegate.as_ref()
+        ],
+        bump
+    )]
+    pub delegate_delegation: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub delegator: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump,
+        constraint = !config.is_paused(PAUSE_POLLS) @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct VoteDelegated {
+    pub channel_id: u64,
+    pub delegator: Pubkey,
+    pub delegate: Pubkey,
+}
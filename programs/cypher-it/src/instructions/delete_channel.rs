@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
-use crate::constants::CHANNEL_SEED;
+use crate::constants::{CHANNEL_SEED, PAUSE_WITHDRAWALS, STATE_SEED};
 use crate::errors::ErrorCode;
-use crate::states::Channel;
+use crate::states::{Channel, Config};
 
 pub fn delete_channel(ctx: Context<DeleteChannelCtx>, channel_id: u64) -> Result<()> {
     let channel = &ctx.accounts.channel;
@@ -43,6 +43,14 @@ pub struct DeleteChannelCtx<'info> {
 
     #[account(mut)]
     pub creator: Signer<'info>,
+
+    // Closing the channel pays out its vault balance along with the rent
+    #[account(
+        seeds = [STATE_SEED],
+        bump,
+        constraint = !config.is_paused(PAUSE_WITHDRAWALS) @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
 }
 
 #[event]
@@ -1,6 +1,7 @@
 // instructions/end_poll.rs
 use anchor_lang::prelude::*;
-use crate::states::{Poll, Channel, Membership};
+use crate::constants::{MEMBER_STATS_SEED, PAUSE_POLLS, STATE_SEED};
+use crate::states::{Poll, Channel, Config, Membership, MemberStats};
 use crate::errors::ErrorCode;
 
 #[derive(Accounts)]
@@ -17,10 +18,26 @@ pub struct EndPollCtx<'info> {
     )]
     pub channel: Account<'info, Channel>,
 
-    // Optional: target membership for kick/mute actions
+    // Optional so anyone can end an expired base-layer poll; touched when a member passes it
+    #[account(
+        mut,
+        seeds = [MEMBER_STATS_SEED, poll.channel_id.to_le_bytes().as_ref(), user.key().as_ref()],
+        bump = member_stats.bump,
+    )]
+    pub member_stats: Option<Account<'info, MemberStats>>,
+
+    // Optional: target membership for kick/mute actions. Required to end one early,
+    // since its duplicate-poll lock runs until the original end time
     #[account(mut)]
     pub target_membership: Option<Account<'info, Membership>>,
 
+    #[account(
+        seeds = [STATE_SEED],
+        bump,
+        constraint = !config.is_paused(PAUSE_POLLS) @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -39,17 +56,31 @@ pub fn end_poll(ctx: Context<EndPollCtx>) -> Result<()> {
     // Check if poll is already ended
     require!(!poll.ended, ErrorCode::PollAlreadyEnded);
 
+    // Release the target now instead of at the original end time
+    if poll.is_moderation() && clock.unix_timestamp <= poll.end_time {
+        let Some(target_membership) = &mut ctx.accounts.target_membership else {
+            return Err(ErrorCode::MissingTarget.into());
+        };
+        require!(
+            target_membership.channel_id == poll.channel_id
+                && Some(target_membership.member) == poll.target,
+            ErrorCode::InvalidTarget
+        );
+        target_membership.targeted_until = clock.unix_timestamp;
+    }
+
     poll.ended = true;
+    poll.passed = poll.is_passed();
+    if let Some(member_stats) = &mut ctx.accounts.member_stats {
+        member_stats.touch(clock.unix_timestamp);
+    }
 
     // Execute poll action based on type and results
     if poll.poll_type == 0 || poll.poll_type == 1 {
         // Kick or Mute poll
         if poll.votes.len() >= 2 {
-            let yes_votes = poll.votes[0];
-            let no_votes = poll.votes[1];
-
             // Check if "Yes" wins and meets required votes
-            if yes_votes > no_votes && poll.total_votes >= poll.required_votes {
+            if poll.passed {
                 msg!("Poll passed! Action will be executed.");
                 
                 // Execute action based on poll type
@@ -0,0 +1,68 @@
+// instructions/finalize_poll.rs
+use anchor_lang::prelude::*;
+use ephemeral_rollups_sdk::anchor::commit;
+use ephemeral_rollups_sdk::ephem::commit_and_undelegate_accounts;
+use crate::constants::{PAUSE_POLLS, STATE_SEED};
+use crate::errors::ErrorCode;
+use crate::states::{Config, Poll};
+
+#[commit]
+#[derive(Accounts)]
+pub struct FinalizePollCtx<'info> {
+    // Anyone may crank an expired poll
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(mut)]
+    pub poll: Account<'info, Poll>,
+
+    // Read-only, so it can be passed in the rollup without being delegated
+    #[account(
+        seeds = [STATE_SEED],
+        bump,
+        constraint = !config.is_paused(PAUSE_POLLS) @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+}
+
+pub fn finalize_poll(ctx: Context<FinalizePollCtx>) -> Result<()> {
+    let poll = &mut ctx.accounts.poll;
+    let clock = Clock::get()?;
+
+    require!(!poll.ended, ErrorCode::PollAlreadyEnded);
+    require!(poll.is_expired(clock.unix_timestamp), ErrorCode::PollStillActive);
+
+    poll.ended = true;
+    poll.passed = poll.is_passed();
+
+    emit!(PollFinalized {
+        poll: poll.key(),
+        channel_id: poll.channel_id,
+        poll_type: poll.poll_type,
+        total_votes: poll.total_votes,
+        passed: poll.passed,
+        finalized_at: clock.unix_timestamp,
+    });
+
+    // Serialize the outcome before handing the account back to the base layer
+    poll.exit(&crate::ID)?;
+    commit_and_undelegate_accounts(
+        &ctx.accounts.payer,
+        vec![&ctx.accounts.poll.to_account_info()],
+        &ctx.accounts.magic_context,
+        &ctx.accounts.magic_program,
+    )?;
+
+    msg!("Poll finalized, committed and undelegated");
+    Ok(())
+}
+
+#[event]
+pub struct PollFinalized {
+    pub poll: Pubkey,
+    pub channel_id: u64,
+    pub poll_type: u8,
+    pub total_votes: u64,
+    pub passed: bool,
+    pub finalized_at: i64,
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::constants::CHANNEL_SEED;
+use crate::states::Channel;
+
+#[derive(Accounts)]
+#[instruction(channel_id: u64)]
+pub struct GetChannelCtx<'info> {
+    #[account(
+        seeds = [CHANNEL_SEED, channel_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub channel: Account<'info, Channel>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ChannelView {
+    pub id: u64,
+    pub name: String,
+    pub description: String,
+    pub img_url: String,
+    pub cost: u64,
+    pub creator: Pubkey,
+    pub is_private: bool,
+    pub member_count: u64,
+    pub join_count: u64,
+    pub poll_count: u64,
+    pub moderators: Vec<Pubkey>,
+    pub min_poll_reputation: u64,
+    pub soulbound: bool,
+    pub transferable: bool,
+    pub max_members: u64,
+    pub transfer_royalty: u64,
+    pub fee_override_bps: Option<u64>,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+pub fn get_channel(ctx: Context<GetChannelCtx>, _channel_id: u64) -> Result<ChannelView> {
+    let channel = &ctx.accounts.channel;
+    Ok(ChannelView {
+        id: channel.id,
+        name: channel.name.clone(),
+        description: channel.description.clone(),
+        img_url: channel.img_url.clone(),
+        cost: channel.cost,
+        creator: channel.creator,
+        is_private: channel.is_private,
+        member_count: channel.member_count,
+        join_count: channel.join_count,
+        poll_count: channel.poll_count,
+        moderators: channel.moderators.clone(),
+        min_poll_reputation: channel.min_poll_reputation,
+        soulbound: channel.soulbound,
+        transferable: channel.transferable,
+        max_members: channel.max_members,
+        transfer_royalty: channel.transfer_royalty,
+        fee_override_bps: channel.fee_override_bps,
+        created_at: channel.created_at,
+        updated_at: channel.updated_at,
+    })
+}
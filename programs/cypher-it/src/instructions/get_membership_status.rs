@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::constants::MEMBERSHIP_SEED;
+use crate::states::Membership;
+
+#[derive(Accounts)]
+#[instruction(channel_id: u64, member: Pubkey)]
+pub struct GetMembershipStatusCtx<'info> {
+    /// CHECK: May not exist yet; an empty account means the wallet never joined
+    #[account(
+        seeds = [MEMBERSHIP_SEED, channel_id.to_le_bytes().as_ref(), member.as_ref()],
+        bump,
+    )]
+    pub membership: UncheckedAccount<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct MembershipStatus {
+    pub channel_id: u64,
+    pub member: Pubkey,
+    pub joined: bool,
+    pub joined_at: i64,
+    pub is_muted: bool,
+    pub muted_until: i64,
+    pub nft_mint: Option<Pubkey>,
+}
+
+pub fn get_membership_status(
+    ctx: Context<GetMembershipStatusCtx>,
+    channel_id: u64,
+    member: Pubkey,
+) -> Result<MembershipStatus> {
+    let info = &ctx.accounts.membership;
+    if info.data_is_empty() {
+        return Ok(MembershipStatus {
+            channel_id,
+            member,
+            ..Default::default()
+        });
+    }
+
+    let membership = Membership::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+    let current_time = Clock::get()?.unix_timestamp;
+    Ok(MembershipStatus {
+        channel_id,
+        member,
+        joined: membership.joined,
+        joined_at: membership.joined_at,
+        is_muted: membership.is_muted(current_time),
+        muted_until: membership.muted_until,
+        nft_mint: membership.nft_mint,
+    })
+}
@@ -0,0 +1,45 @@
+// instructions/get_poll_results.rs
+use anchor_lang::prelude::*;
+use crate::states::Poll;
+
+#[derive(Accounts)]
+pub struct GetPollResultsCtx<'info> {
+    pub poll: Account<'info, Poll>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PollResults {
+    pub channel_id: u64,
+    pub poll_type: u8,
+    pub options: Vec<String>,
+    pub votes: Vec<u64>,
+    pub total_votes: u64,
+    pub required_votes: u64,
+    pub member_count_snapshot: u64,
+    pub target: Option<Pubkey>,
+    pub winning_option: Option<u64>, // None when there are no votes or a tie
+    pub passed: bool,
+    pub ended: bool,
+    pub time_remaining: i64, // Seconds until end_time, 0 once expired
+}
+
+pub fn get_poll_results(ctx: Context<GetPollResultsCtx>) -> Result<PollResults> {
+    let poll = &ctx.accounts.poll;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    Ok(PollResults {
+        channel_id: poll.channel_id,
+        poll_type: poll.poll_type,
+        options: poll.options.clone(),
+        votes: poll.votes.clone(),
+        total_votes: poll.total_votes,
+        required_votes: poll.required_votes,
+        member_count_snapshot: poll.member_count_snapshot,
+        target: poll.target,
+        winning_option: poll.winning_option(),
+        // Ongoing polls report the outcome they would have if ended now
+        passed: if poll.ended { poll.passed } else { poll.is_passed() },
+        ended: poll.ended,
+        time_remaining: poll.end_time.saturating_sub(current_time).max(0),
+    })
+}
@@ -1,20 +1,36 @@
 use anchor_lang::prelude::*;
+use crate::constants::PROFILE_SEED;
 use crate::states::*;
 
 #[derive(Accounts)]
+#[instruction(user: Pubkey)]
 pub struct GetProfileCtx<'info> {
     #[account(
-        seeds = [b"profile", user.key().as_ref()],
+        seeds = [PROFILE_SEED, user.as_ref()],
         bump,
     )]
     pub profile: Account<'info, Profile>,
-    pub user: Signer<'info>,
 }
 
-pub fn get_profile(ctx: Context<GetProfileCtx>) -> Result<()> {
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ProfileView {
+    pub owner: Pubkey,
+    pub display_name: String,
+    pub bio: String,
+    pub avatar_uri: String,
+    pub created_at: i64,
+    pub reputation: u64,
+}
+
+// Read-only: the result is returned via set_return_data for simulation and CPI
+pub fn get_profile(ctx: Context<GetProfileCtx>, _user: Pubkey) -> Result<ProfileView> {
     let profile = &ctx.accounts.profile;
-    msg!("Display Name: {}", profile.display_name);
-    msg!("Bio: {}", profile.bio);
-    msg!("Avatar: {}", profile.avatar_uri);
-    Ok(())
+    Ok(ProfileView {
+        owner: profile.owner,
+        display_name: profile.display_name.clone(),
+        bio: profile.bio.clone(),
+        avatar_uri: profile.avatar_uri.clone(),
+        created_at: profile.created_at,
+        reputation: profile.reputation,
+    })
 }
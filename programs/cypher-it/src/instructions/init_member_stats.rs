@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use crate::constants::{ANCHOR_DISCRIMINATOR_SIZE, MEMBERSHIP_SEED, MEMBER_STATS_SEED, PAUSE_GENERAL, STATE_SEED};
+use crate::errors::ErrorCode;
+use crate::states::{Config, MemberStats, Membership};
+
+// For members who joined before stats were tracked. Anyone may pay, since the
+// instructions that update stats require the account to exist
+pub fn init_member_stats(ctx: Context<InitMemberStatsCtx>, channel_id: u64) -> Result<()> {
+    let member_stats = &mut ctx.accounts.member_stats;
+
+    member_stats.channel_id = channel_id;
+    member_stats.member = ctx.accounts.member.key();
+    member_stats.last_active_at = Clock::get()?.unix_timestamp;
+    member_stats.bump = ctx.bumps.member_stats;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(channel_id: u64)]
+pub struct InitMemberStatsCtx<'info> {
+    #[account(
+        seeds = [STATE_SEED],
+        bump,
+        constraint = !config.is_paused(PAUSE_GENERAL) @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [
+            MEMBERSHIP_SEED,
+            channel_id.to_le_bytes().as_ref(),
+            member.key().as_ref()
+        ],
+        bump,
+        constraint = membership.joined @ ErrorCode::NotChannelMember
+    )]
+    pub membership: Account<'info, Membership>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = ANCHOR_DISCRIMINATOR_SIZE + MemberStats::INIT_SPACE,
+        seeds = [
+            MEMBER_STATS_SEED,
+            channel_id.to_le_bytes().as_ref(),
+            member.key().as_ref()
+        ],
+        bump
+    )]
+    pub member_stats: Account<'info, MemberStats>,
+
+    /// CHECK: Bound to the membership and stats PDAs by their seeds
+    pub member: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
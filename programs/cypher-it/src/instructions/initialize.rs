@@ -1,7 +1,15 @@
 use anchor_lang::prelude::*;
-use crate::constants::{ANCHOR_DISCRIMINATOR_SIZE, STATE_SEED};
+use crate::constants::{
+    ANCHOR_DISCRIMINATOR_SIZE,
+    BPS_PER_PERCENT,
+    CONFIG_VERSION,
+    DEFAULT_PLATFORM_FEE_BPS,
+    FEE_SCHEDULE_SEED,
+    STATE_SEED,
+    TREASURY_SEED
+};
 use crate::errors::ErrorCode;
-use crate::states::Config;
+use crate::states::{Config, FeeSchedule, PlatformTreasury};
 
 pub fn initialize(ctx: Context<InitializeCtx>) -> Result<()> {
     let config = &mut ctx.accounts.config;
@@ -13,14 +21,29 @@ pub fn initialize(ctx: Context<InitializeCtx>) -> Result<()> {
 
     config.initialized = true;
     config.total_channels = 0;
-    config.platform_fee = 5; // 5% default platform fee
+    config.platform_fee = DEFAULT_PLATFORM_FEE_BPS / BPS_PER_PERCENT; // Legacy mirror of the base rate
     config.fee_recipient = owner.key();
     config.owner = owner.key();
+    config.pending_owner = None;
+    config.guardian = owner.key();
+    config.paused = 0;
+    config.version = CONFIG_VERSION;
+
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.total_collected = 0;
+    treasury.total_withdrawn = 0;
+    treasury.bump = ctx.bumps.treasury;
+
+    let fee_schedule = &mut ctx.accounts.fee_schedule;
+    fee_schedule.base_fee_bps = DEFAULT_PLATFORM_FEE_BPS;
+    fee_schedule.tiers = Vec::new();
+    fee_schedule.bump = ctx.bumps.fee_schedule;
 
     emit!(ConfigInitialized {
         owner: owner.key(),
-        platform_fee: 5,
+        platform_fee: config.platform_fee,
         fee_recipient: owner.key(),
+        platform_fee_bps: DEFAULT_PLATFORM_FEE_BPS,
     });
 
     Ok(())
@@ -36,6 +59,24 @@ pub struct InitializeCtx<'info> {
         bump
     )]
     pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = ANCHOR_DISCRIMINATOR_SIZE + PlatformTreasury::INIT_SPACE,
+        seeds = [TREASURY_SEED],
+        bump
+    )]
+    pub treasury: Account<'info, PlatformTreasury>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = ANCHOR_DISCRIMINATOR_SIZE + FeeSchedule::INIT_SPACE,
+        seeds = [FEE_SCHEDULE_SEED],
+        bump
+    )]
+    pub fee_schedule: Account<'info, FeeSchedule>,
     
     #[account(mut)]
     pub owner: Signer<'info>,
@@ -46,6 +87,7 @@ pub struct InitializeCtx<'info> {
 #[event]
 pub struct ConfigInitialized {
     pub owner: Pubkey,
-    pub platform_fee: u64,
+    pub platform_fee: u64, // Percentage, kept for existing consumers
     pub fee_recipient: Pubkey,
+    pub platform_fee_bps: u64,
 }
\ No newline at end of file
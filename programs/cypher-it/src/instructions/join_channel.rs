@@ -1,22 +1,38 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program;
 use anchor_spl::associated_token::AssociatedToken;
-use anchor_spl::token::{self, initialize_mint, mint_to, Mint, MintTo, Token, TokenAccount};
+use anchor_spl::metadata::Metadata;
+use anchor_spl::token::{mint_to, Mint, MintTo, Token, TokenAccount};
 use crate::constants::{
     ANCHOR_DISCRIMINATOR_SIZE, 
+    BAN_SEED,
     CHANNEL_SEED, 
+    FEE_SCHEDULE_SEED,
     MEMBERSHIP_SEED,
-    STATE_SEED
+    MEMBERSHIP_VERSION,
+    MEMBER_STATS_SEED,
+    NFT_MINT_SEED,
+    PAUSE_JOINS,
+    PLATFORM_BAN_SEED,
+    STATE_SEED,
+    TREASURY_SEED
 };
 use crate::errors::ErrorCode;
-use crate::states::{Channel, Config, Membership};
+use crate::states::{Channel, Config, FeeSchedule, MemberStats, Membership, PlatformTreasury};
+use crate::utils::{
+    calculate_platform_fee,
+    create_membership_metadata,
+    ensure_not_banned,
+    lock_membership_nft
+};
 
 pub fn join_channel(ctx: Context<JoinChannelCtx>, channel_id: u64) -> Result<()> {
-    let config = &ctx.accounts.config;
+    let fee_schedule = &ctx.accounts.fee_schedule;
     let channel = &mut ctx.accounts.channel;
     let membership = &mut ctx.accounts.membership;
+    let member_stats = &mut ctx.accounts.member_stats;
     let member = &ctx.accounts.member;
-    let fee_recipient = &ctx.accounts.fee_recipient;
+    let treasury = &mut ctx.accounts.treasury;
 
     // Validation
     if channel.id != channel_id {
@@ -27,34 +43,36 @@ pub fn join_channel(ctx: Context<JoinChannelCtx>, channel_id: u64) -> Result<()>
         return Err(ErrorCode::AlreadyJoined.into());
     }
 
+    let current_time = Clock::get()?.unix_timestamp;
+    ensure_not_banned(&ctx.accounts.platform_ban, current_time)?;
+    ensure_not_banned(&ctx.accounts.ban, current_time)?;
+
+    if channel.max_members > 0 && channel.member_count >= channel.max_members {
+        return Err(ErrorCode::ChannelFull.into());
+    }
+
     let cost = channel.cost;
     if **member.to_account_info().lamports.borrow() < cost {
         return Err(ErrorCode::InsufficientFunds.into());
     }
 
     // Calculate fees
-    let platform_fee_amount = cost
-        .checked_mul(config.platform_fee)
-        .ok_or(ErrorCode::ArithmeticOverflow)?
-        .checked_div(100)
-        .ok_or(ErrorCode::ArithmeticOverflow)?;
-    
-    let creator_amount = cost
-        .checked_sub(platform_fee_amount)
-        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    let (platform_fee_amount, creator_amount) =
+        calculate_platform_fee(cost, fee_schedule.fee_bps_for(channel))?;
 
-    // Transfer platform fee to fee recipient
+    // Transfer platform fee to the treasury
     if platform_fee_amount > 0 {
         system_program::transfer(
             CpiContext::new(
                 ctx.accounts.system_program.to_account_info(),
                 system_program::Transfer {
                     from: member.to_account_info(),
-                    to: fee_recipient.to_account_info(),
+                    to: treasury.to_account_info(),
                 },
             ),
             platform_fee_amount,
         )?;
+        treasury.record_fee(platform_fee_amount)?;
     }
 
     // Transfer remaining amount to channel creator
@@ -75,48 +93,27 @@ pub fn join_channel(ctx: Context<JoinChannelCtx>, channel_id: u64) -> Result<()>
     membership.channel_id = channel_id;
     membership.member = member.key();
     membership.joined = true;
-    membership.joined_at = Clock::get()?.unix_timestamp;
+    membership.joined_at = current_time;
+    membership.version = MEMBERSHIP_VERSION;
+
+    // Start tracking activity for this member, or keep counting for a returning one
+    if member_stats.member == Pubkey::default() {
+        member_stats.channel_id = channel_id;
+        member_stats.member = member.key();
+        member_stats.bump = ctx.bumps.member_stats;
+    }
+    member_stats.total_paid = member_stats.total_paid.saturating_add(cost);
+    member_stats.last_active_at = membership.joined_at;
 
-    // Seeds for PDA signing
+    // The channel PDA signs as the NFT's authority
     let channel_id_bytes = channel_id.to_le_bytes();
-    let member_key = member.key();
     let seeds = &[
-        MEMBERSHIP_SEED,
+        CHANNEL_SEED,
         channel_id_bytes.as_ref(),
-        member_key.as_ref(),
-        &[ctx.bumps.membership],
+        &[ctx.bumps.channel],
     ];
     let signer = &[&seeds[..]];
 
-    // Initialize the mint (decimals = 0, authority = membership)
-    initialize_mint(
-        CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            token::InitializeMint {
-                mint: ctx.accounts.nft_mint.to_account_info(),
-                rent: ctx.accounts.rent.to_account_info(),
-            },
-        ),
-        0, // decimals
-        &membership.key(),
-        Some(&membership.key()),
-    )?;
-
-    // Create associated token account for the member
-    anchor_spl::associated_token::create(
-        CpiContext::new(
-            ctx.accounts.associated_token_program.to_account_info(),
-            anchor_spl::associated_token::Create {
-                payer: member.to_account_info(),
-                associated_token: ctx.accounts.nft_token_account.to_account_info(),
-                authority: member.to_account_info(),
-                mint: ctx.accounts.nft_mint.to_account_info(),
-                system_program: ctx.accounts.system_program.to_account_info(),
-                token_program: ctx.accounts.token_program.to_account_info(),
-           },
-        ),
-    )?;
-
     // Mint 1 NFT to the member's associated token account
     mint_to(
         CpiContext::new_with_signer(
@@ -124,13 +121,42 @@ pub fn join_channel(ctx: Context<JoinChannelCtx>, channel_id: u64) -> Result<()>
             MintTo {
                 mint: ctx.accounts.nft_mint.to_account_info(),
                 to: ctx.accounts.nft_token_account.to_account_info(),
-                authority: membership.to_account_info(),
+                authority: channel.to_account_info(),
             },
             signer,
         ),
         1,
     )?;
 
+    // Number this membership and give the NFT a wallet-visible name and image
+    channel.join_count = channel.join_count
+        .checked_add(1)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    create_membership_metadata(
+        ctx.accounts.token_metadata_program.to_account_info(),
+        ctx.accounts.nft_metadata.to_account_info(),
+        ctx.accounts.nft_mint.to_account_info(),
+        channel.to_account_info(),
+        member.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.rent.to_account_info(),
+        &channel.name,
+        &channel.img_url,
+        channel.join_count,
+        signer,
+    )?;
+
+    if channel.soulbound {
+        lock_membership_nft(
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.nft_mint.to_account_info(),
+            ctx.accounts.nft_token_account.to_account_info(),
+            member.to_account_info(),
+            channel.to_account_info(),
+            signer,
+        )?;
+    }
+
     // Update membership with NFT mint address
     membership.nft_mint = Some(ctx.accounts.nft_mint.key());
 
@@ -157,7 +183,8 @@ pub fn join_channel(ctx: Context<JoinChannelCtx>, channel_id: u64) -> Result<()>
 pub struct JoinChannelCtx<'info> {
     #[account(
         seeds = [STATE_SEED],
-        bump
+        bump,
+        constraint = !config.is_paused(PAUSE_JOINS) @ ErrorCode::ProgramPaused
     )]
     pub config: Account<'info, Config>,
 
@@ -171,8 +198,9 @@ pub struct JoinChannelCtx<'info> {
     )]
     pub channel: Account<'info, Channel>,
 
+    // Re-used when a former member rejoins, so mutes and cooldowns carry over
     #[account(
-        init,
+        init_if_needed,
         payer = member,
         space = ANCHOR_DISCRIMINATOR_SIZE + Membership::INIT_SPACE,
         seeds = [
@@ -184,14 +212,54 @@ pub struct JoinChannelCtx<'info> {
     )]
     pub membership: Account<'info, Membership>,
 
-    /// Mint will be created via CPI
-    #[account(mut)]
+    #[account(
+        init_if_needed,
+        payer = member,
+        space = ANCHOR_DISCRIMINATOR_SIZE + MemberStats::INIT_SPACE,
+        seeds = [
+            MEMBER_STATS_SEED,
+            channel_id.to_le_bytes().as_ref(),
+            member.key().as_ref()
+        ],
+        bump
+    )]
+    pub member_stats: Account<'info, MemberStats>,
+
+    // Keyed by this join's number too, since a mint can never be closed and the
+    // same wallet may join again later
+    #[account(
+        init,
+        payer = member,
+        seeds = [
+            NFT_MINT_SEED,
+            channel_id.to_le_bytes().as_ref(),
+            member.key().as_ref(),
+            (channel.join_count + 1).to_le_bytes().as_ref()
+        ],
+        bump,
+        mint::decimals = 0,
+        mint::authority = channel,
+        mint::freeze_authority = channel
+    )]
     pub nft_mint: Account<'info, Mint>,
 
-    /// Token account will be created via CPI
-    #[account(mut)]
+    #[account(
+        init,
+        payer = member,
+        associated_token::mint = nft_mint,
+        associated_token::authority = member
+    )]
     pub nft_token_account: Account<'info, TokenAccount>,
 
+    /// CHECK: Metaplex metadata PDA for the NFT mint, created via CPI
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), nft_mint.key().as_ref()],
+        bump,
+        seeds::program = token_metadata_program.key()
+    )]
+    pub nft_metadata: UncheckedAccount<'info>,
+
     #[account(mut)]
     pub member: Signer<'info>,
 
@@ -202,15 +270,40 @@ pub struct JoinChannelCtx<'info> {
     )]
     pub creator: UncheckedAccount<'info>,
 
-    /// CHECK: Fee recipient - validated by config.fee_recipient
+    #[account(
+        seeds = [FEE_SCHEDULE_SEED],
+        bump = fee_schedule.bump
+    )]
+    pub fee_schedule: Account<'info, FeeSchedule>,
+
     #[account(
         mut,
-        constraint = fee_recipient.key() == config.fee_recipient
+        seeds = [TREASURY_SEED],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, PlatformTreasury>,
+
+    /// CHECK: Ban PDA for this wallet, usually uninitialized - checked in ensure_not_banned
+    #[account(
+        seeds = [
+            BAN_SEED,
+            channel_id.to_le_bytes().as_ref(),
+            member.key().as_ref()
+        ],
+        bump
+    )]
+    pub ban: UncheckedAccount<'info>,
+
+    /// CHECK: Platform ban PDA for this wallet - checked in ensure_not_banned
+    #[account(
+        seeds = [PLATFORM_BAN_SEED, member.key().as_ref()],
+        bump
     )]
-    pub fee_recipient: UncheckedAccount<'info>,
+    pub platform_ban: UncheckedAccount<'info>,
 
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_metadata_program: Program<'info, Metadata>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
 }
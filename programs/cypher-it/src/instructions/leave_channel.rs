@@ -1,8 +1,11 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
 use crate::constants::{CHANNEL_SEED, MEMBERSHIP_SEED};
 use crate::errors::ErrorCode;
 use crate::states::{Channel, Membership};
+use crate::utils::burn_membership_nft;
 
+// Not gated by pause bits: leaving moves no funds, and members must always be able to exit
 pub fn leave_channel(ctx: Context<LeaveChannelCtx>, channel_id: u64) -> Result<()> {
     let channel = &mut ctx.accounts.channel;
     let membership = &mut ctx.accounts.membership;
@@ -21,6 +24,33 @@ pub fn leave_channel(ctx: Context<LeaveChannelCtx>, channel_id: u64) -> Result<(
         return Err(ErrorCode::NotChannelMember.into());
     }
 
+    // Soulbound NFTs are burned so they cannot outlive the membership
+    if channel.soulbound {
+        let (Some(nft_mint), Some(nft_token_account), Some(token_program)) = (
+            &ctx.accounts.nft_mint,
+            &ctx.accounts.nft_token_account,
+            &ctx.accounts.token_program,
+        ) else {
+            return Err(ErrorCode::MissingMembershipNft.into());
+        };
+
+        let channel_id_bytes = channel_id.to_le_bytes();
+        let seeds = &[
+            CHANNEL_SEED,
+            channel_id_bytes.as_ref(),
+            &[ctx.bumps.channel],
+        ];
+
+        burn_membership_nft(
+            token_program.to_account_info(),
+            nft_mint.to_account_info(),
+            nft_token_account.to_account_info(),
+            channel.to_account_info(),
+            &[&seeds[..]],
+        )?;
+        membership.nft_mint = None;
+    }
+
     // Update membership status
     membership.joined = false;
 
@@ -64,6 +94,22 @@ pub struct LeaveChannelCtx<'info> {
     )]
     pub membership: Account<'info, Membership>,
 
+    // Required for soulbound channels
+    #[account(
+        mut,
+        constraint = membership.nft_mint == Some(nft_mint.key()) @ ErrorCode::InvalidMembershipNft
+    )]
+    pub nft_mint: Option<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        constraint = membership.nft_mint == Some(nft_token_account.mint) @ ErrorCode::InvalidMembershipNft,
+        constraint = nft_token_account.owner == member.key() @ ErrorCode::InvalidMembershipNft
+    )]
+    pub nft_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
     pub member: Signer<'info>,
 }
 
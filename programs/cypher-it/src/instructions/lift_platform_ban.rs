@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::constants::{PLATFORM_BAN_SEED, STATE_SEED};
+use crate::errors::ErrorCode;
+use crate::states::{Ban, Config};
+
+pub fn lift_platform_ban(ctx: Context<LiftPlatformBanCtx>, wallet: Pubkey) -> Result<()> {
+    emit!(PlatformBanLifted {
+        wallet,
+        lifted_by: ctx.accounts.owner.key(),
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct LiftPlatformBanCtx<'info> {
+    #[account(
+        seeds = [STATE_SEED],
+        bump,
+        constraint = config.owner == owner.key() @ ErrorCode::UnauthorizedOwner
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        close = rent_receiver,
+        seeds = [PLATFORM_BAN_SEED, wallet.as_ref()],
+        bump = platform_ban.bump
+    )]
+    pub platform_ban: Account<'info, Ban>,
+
+    pub owner: Signer<'info>,
+
+    /// CHECK: Receives the ban account's rent
+    #[account(mut)]
+    pub rent_receiver: UncheckedAccount<'info>,
+}
+
+#[event]
+pub struct PlatformBanLifted {
+    pub wallet: Pubkey,
+    pub lifted_by: Pubkey,
+}
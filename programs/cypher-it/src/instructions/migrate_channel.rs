@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::constants::{ANCHOR_DISCRIMINATOR_SIZE, CHANNEL_SEED, PAUSE_GENERAL, STATE_SEED};
+use crate::errors::ErrorCode;
+use crate::states::{Channel, Config};
+use crate::utils::migrate_account;
+
+pub fn migrate_channel(ctx: Context<MigrateChannelCtx>, _channel_id: u64) -> Result<()> {
+    let account = ctx.accounts.channel.to_account_info();
+
+    migrate_account::<Channel>(
+        &account,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        ANCHOR_DISCRIMINATOR_SIZE + Channel::INIT_SPACE,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(channel_id: u64)]
+pub struct MigrateChannelCtx<'info> {
+    #[account(
+        seeds = [STATE_SEED],
+        bump,
+        constraint = !config.is_paused(PAUSE_GENERAL) @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: Old layouts can't deserialize yet - owner and discriminator are checked in migrate_account
+    #[account(
+        mut,
+        seeds = [
+            CHANNEL_SEED,
+            channel_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub channel: UncheckedAccount<'info>,
+
+    // Anyone may pay to upgrade an account, the contents are unchanged
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use crate::constants::{ANCHOR_DISCRIMINATOR_SIZE, STATE_SEED};
+use crate::states::Config;
+use crate::utils::migrate_account;
+
+pub fn migrate_config(ctx: Context<MigrateConfigCtx>) -> Result<()> {
+    let account = ctx.accounts.config.to_account_info();
+
+    migrate_account::<Config>(
+        &account,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        ANCHOR_DISCRIMINATOR_SIZE + Config::INIT_SPACE,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateConfigCtx<'info> {
+    /// CHECK: Old layouts can't deserialize yet - owner and discriminator are checked in migrate_account
+    #[account(
+        mut,
+        seeds = [STATE_SEED],
+        bump
+    )]
+    pub config: UncheckedAccount<'info>,
+
+    // Anyone may pay to upgrade an account, the contents are unchanged
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::constants::{ANCHOR_DISCRIMINATOR_SIZE, MEMBERSHIP_SEED, PAUSE_GENERAL, STATE_SEED};
+use crate::errors::ErrorCode;
+use crate::states::{Config, Membership};
+use crate::utils::migrate_account;
+
+pub fn migrate_membership(ctx: Context<MigrateMembershipCtx>, _channel_id: u64, _member: Pubkey) -> Result<()> {
+    let account = ctx.accounts.membership.to_account_info();
+
+    migrate_account::<Membership>(
+        &account,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        ANCHOR_DISCRIMINATOR_SIZE + Membership::INIT_SPACE,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(channel_id: u64, member: Pubkey)]
+pub struct MigrateMembershipCtx<'info> {
+    #[account(
+        seeds = [STATE_SEED],
+        bump,
+        constraint = !config.is_paused(PAUSE_GENERAL) @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: Old layouts can't deserialize yet - owner and discriminator are checked in migrate_account
+    #[account(
+        mut,
+        seeds = [
+            MEMBERSHIP_SEED,
+            channel_id.to_le_bytes().as_ref(),
+            member.as_ref()
+        ],
+        bump
+    )]
+    pub membership: UncheckedAccount<'info>,
+
+    // Anyone may pay to upgrade an account, the contents are unchanged
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::constants::{ANCHOR_DISCRIMINATOR_SIZE, PAUSE_GENERAL, STATE_SEED};
+use crate::errors::ErrorCode;
+use crate::states::{Config, Poll};
+use crate::utils::migrate_account;
+
+pub fn migrate_poll(ctx: Context<MigratePollCtx>) -> Result<()> {
+    let account = ctx.accounts.poll.to_account_info();
+
+    migrate_account::<Poll>(
+        &account,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        ANCHOR_DISCRIMINATOR_SIZE + Poll::INIT_SPACE,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigratePollCtx<'info> {
+    #[account(
+        seeds = [STATE_SEED],
+        bump,
+        constraint = !config.is_paused(PAUSE_GENERAL) @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: Old layouts can't deserialize yet - owner and discriminator are checked in migrate_account
+    #[account(
+        mut,
+        owner = crate::ID
+    )]
+    pub poll: UncheckedAccount<'info>,
+
+    // Anyone may pay to upgrade an account, the contents are unchanged
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::constants::{ANCHOR_DISCRIMINATOR_SIZE, PAUSE_GENERAL, PROFILE_SEED, STATE_SEED};
+use crate::errors::ErrorCode;
+use crate::states::{Config, Profile};
+use crate::utils::migrate_account;
+
+pub fn migrate_profile(ctx: Context<MigrateProfileCtx>, _owner: Pubkey) -> Result<()> {
+    let account = ctx.accounts.profile.to_account_info();
+
+    migrate_account::<Profile>(
+        &account,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        ANCHOR_DISCRIMINATOR_SIZE + Profile::INIT_SPACE,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(owner: Pubkey)]
+pub struct MigrateProfileCtx<'info> {
+    #[account(
+        seeds = [STATE_SEED],
+        bump,
+        constraint = !config.is_paused(PAUSE_GENERAL) @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: Old layouts can't deserialize yet - owner and discriminator are checked in migrate_account
+    #[account(
+        mut,
+        seeds = [PROFILE_SEED, owner.as_ref()],
+        bump
+    )]
+    pub profile: UncheckedAccount<'info>,
+
+    // Anyone may pay to upgrade an account, the contents are unchanged
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -2,32 +2,148 @@ pub mod initialize;
 pub mod create_channel;
 pub mod join_channel;
 pub mod leave_channel;
+pub mod set_membership_policy;
+pub mod claim_membership;
+pub mod verify_membership;
+pub mod init_member_stats;
 pub mod update_channel;
 pub mod delete_channel;
 pub mod set_platform_fee;
 pub mod set_fee_recipient;
 pub mod withdraw;
+pub mod create_treasury;
+pub mod create_fee_schedule;
+pub mod set_fee_tiers;
+pub mod set_channel_fee_override;
+pub mod propose_owner;
+pub mod accept_owner;
+pub mod set_guardian;
+pub mod set_paused;
+pub mod migrate_config;
+pub mod migrate_channel;
+pub mod migrate_membership;
+pub mod migrate_poll;
+pub mod migrate_profile;
+pub mod ban_member;
+pub mod unban_member;
+pub mod platform_ban;
+pub mod lift_platform_ban;
+pub mod set_poll_settings;
+pub mod settle_poll_deposit;
+pub mod withdraw_channel_vault;
+pub mod set_protected_roles;
+pub mod delegate_vote;
+pub mod revoke_vote_delegation;
 pub mod create_profile;
 pub mod get_profile;
+pub mod get_channel;
+pub mod get_membership_status;
+pub mod get_poll_results;
+pub mod update_profile;
+pub mod rename_profile;
+pub mod close_profile;
+pub mod claim_username;
 pub mod create_poll;
 pub mod vote_poll;
 pub mod end_poll;
 pub mod delegate_poll;
 pub mod undelegate_poll;
+pub mod finalize_poll;
+pub mod apply_poll_outcome;
+pub mod settle_poll_reputation;
+pub mod create_message_log;
+pub mod commit_messages;
+pub mod set_relayer;
+pub mod verify_message;
+pub mod create_channel_messages;
+pub mod delegate_channel_messages;
+pub mod undelegate_channel_messages;
+pub mod post_message;
+pub mod add_moderator;
+pub mod remove_moderator;
+pub mod create_channel_pins;
+pub mod delegate_channel_pins;
+pub mod undelegate_channel_pins;
+pub mod pin_message;
+pub mod unpin_message;
+pub mod create_message_reactions;
+pub mod delegate_message_reactions;
+pub mod undelegate_message_reactions;
+pub mod delegate_member_stats;
+pub mod undelegate_member_stats;
+pub mod react;
 
 pub use initialize::*;
 pub use create_channel::*;
 pub use join_channel::*;
 pub use leave_channel::*;
+pub use set_membership_policy::*;
+pub use claim_membership::*;
+pub use verify_membership::*;
+pub use init_member_stats::*;
 pub use update_channel::*;
 pub use delete_channel::*;
 pub use set_platform_fee::*;
 pub use set_fee_recipient::*;
 pub use withdraw::*;
+pub use create_treasury::*;
+pub use create_fee_schedule::*;
+pub use set_fee_tiers::*;
+pub use set_channel_fee_override::*;
+pub use propose_owner::*;
+pub use accept_owner::*;
+pub use set_guardian::*;
+pub use set_paused::*;
+pub use migrate_config::*;
+pub use migrate_channel::*;
+pub use migrate_membership::*;
+pub use migrate_poll::*;
+pub use migrate_profile::*;
+pub use ban_member::*;
+pub use unban_member::*;
+pub use platform_ban::*;
+pub use lift_platform_ban::*;
+pub use set_poll_settings::*;
+pub use settle_poll_deposit::*;
+pub use withdraw_channel_vault::*;
+pub use set_protected_roles::*;
+pub use delegate_vote::*;
+pub use revoke_vote_delegation::*;
 pub use create_profile::*;
 pub use get_profile::*;
+pub use get_channel::*;
+pub use get_membership_status::*;
+pub use get_poll_results::*;
+pub use update_profile::*;
+pub use rename_profile::*;
+pub use close_profile::*;
+pub use claim_username::*;
 pub use create_poll::*;
 pub use vote_poll::*;
 pub use end_poll::*;
 pub use delegate_poll::*;
-pub use undelegate_poll::*;
\ No newline at end of file
+pub use undelegate_poll::*;
+pub use finalize_poll::*;
+pub use apply_poll_outcome::*;
+pub use settle_poll_reputation::*;
+pub use create_message_log::*;
+pub use commit_messages::*;
+pub use set_relayer::*;
+pub use verify_message::*;
+pub use create_channel_messages::*;
+pub use delegate_channel_messages::*;
+pub use undelegate_channel_messages::*;
+pub use post_message::*;
+pub use add_moderator::*;
+pub use remove_moderator::*;
+pub use create_channel_pins::*;
+pub use delegate_channel_pins::*;
+pub use undelegate_channel_pins::*;
+pub use pin_message::*;
+pub use unpin_message::*;
+pub use create_message_reactions::*;
+pub use delegate_message_reactions::*;
+pub use undelegate_message_reactions::*;
+pub use delegate_member_stats::*;
+pub use undelegate_member_stats::*;
+pub use react::*;
\ No newline at end of file
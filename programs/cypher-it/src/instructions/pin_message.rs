@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::constants::{CHANNEL_PINS_SEED, CHANNEL_SEED, MAX_PINNED_MESSAGES, PAUSE_GENERAL, STATE_SEED};
+use crate::errors::ErrorCode;
+use crate::states::{Channel, ChannelPins, Config};
+
+pub fn pin_message(ctx: Context<PinMessageCtx>, channel_id: u64, message_hash: [u8; 32]) -> Result<()> {
+    let channel_pins = &mut ctx.accounts.channel_pins;
+
+    if channel_pins.pins.contains(&message_hash) {
+        return Err(ErrorCode::MessageAlreadyPinned.into());
+    }
+
+    if channel_pins.pins.len() >= MAX_PINNED_MESSAGES {
+        return Err(ErrorCode::PinLimitReached.into());
+    }
+
+    channel_pins.pins.push(message_hash);
+
+    msg!("Message pinned in channel: {}", channel_id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(channel_id: u64)]
+pub struct PinMessageCtx<'info> {
+    #[account(
+        seeds = [STATE_SEED],
+        bump,
+        constraint = !config.is_paused(PAUSE_GENERAL) @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [
+            CHANNEL_SEED,
+            channel_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        constraint = channel.is_moderator(&moderator.key()) @ ErrorCode::UnauthorizedModerator
+    )]
+    pub channel: Account<'info, Channel>,
+
+    #[account(
+        mut,
+        seeds = [
+            CHANNEL_PINS_SEED,
+            channel_id.to_le_bytes().as_ref()
+        ],
+        bump = channel_pins.bump
+    )]
+    pub channel_pins: Account<'info, ChannelPins>,
+
+    pub moderator: Signer<'info>,
+}
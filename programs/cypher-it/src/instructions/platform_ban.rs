@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use crate::constants::{PLATFORM_BAN_SEED, STATE_SEED};
+use crate::errors::ErrorCode;
+use crate::states::Config;
+use crate::utils::{ban_expiry, write_ban};
+
+// Blocks `wallet` from joining any channel. `duration` in seconds, 0 = permanent.
+pub fn platform_ban(ctx: Context<PlatformBanCtx>, wallet: Pubkey, duration: i64) -> Result<()> {
+    let owner = &ctx.accounts.owner;
+
+    if wallet == ctx.accounts.config.owner {
+        return Err(ErrorCode::InvalidTarget.into());
+    }
+
+    let expires_at = ban_expiry(Clock::get()?.unix_timestamp, duration)?;
+
+    let seeds = &[
+        PLATFORM_BAN_SEED,
+        wallet.as_ref(),
+        &[ctx.bumps.platform_ban],
+    ];
+
+    write_ban(
+        &ctx.accounts.platform_ban.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &seeds[..],
+        0,
+        wallet,
+        owner.key(),
+        expires_at,
+    )?;
+
+    emit!(PlatformBanned {
+        wallet,
+        banned_by: owner.key(),
+        expires_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct PlatformBanCtx<'info> {
+    #[account(
+        seeds = [STATE_SEED],
+        bump,
+        constraint = config.owner == owner.key() @ ErrorCode::UnauthorizedOwner
+    )]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: Platform ban PDA, created on first ban and overwritten on later ones
+    #[account(
+        mut,
+        seeds = [PLATFORM_BAN_SEED, wallet.as_ref()],
+        bump
+    )]
+    pub platform_ban: UncheckedAccount<'info>,
+
+    pub owner: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct PlatformBanned {
+    pub wallet: Pubkey,
+    pub banned_by: Pubkey,
+    pub expires_at: i64,
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use crate::constants::{CHANNEL_MESSAGES_SEED, MAX_MESSAGE_LENGTH, MEMBERSHIP_SEED, PAUSE_GENERAL, STATE_SEED};
+use crate::errors::ErrorCode;
+use crate::states::{ChannelMessages, ChatMessage, Config, Membership};
+
+pub fn post_message(ctx: Context<PostMessageCtx>, channel_id: u64, text: String) -> Result<()> {
+    let channel_messages = &mut ctx.accounts.channel_messages;
+    let membership = &ctx.accounts.membership;
+    let author = &ctx.accounts.author;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    if text.is_empty() {
+        return Err(ErrorCode::EmptyMessage.into());
+    }
+
+    if text.len() > MAX_MESSAGE_LENGTH {
+        return Err(ErrorCode::MessageTooLong.into());
+    }
+
+    if membership.is_muted(current_time) {
+        return Err(ErrorCode::MemberMuted.into());
+    }
+
+    channel_messages.push(ChatMessage {
+        author: author.key(),
+        timestamp: current_time,
+        text,
+    })?;
+
+    msg!(
+        "Message {} posted in channel {}",
+        channel_messages.total_posted - 1,
+        channel_id
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(channel_id: u64)]
+pub struct PostMessageCtx<'info> {
+    #[account(
+        seeds = [STATE_SEED],
+        bump,
+        constraint = !config.is_paused(PAUSE_GENERAL) @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [
+            CHANNEL_MESSAGES_SEED,
+            channel_id.to_le_bytes().as_ref()
+        ],
+        bump = channel_messages.bump
+    )]
+    pub channel_messages: Account<'info, ChannelMessages>,
+
+    #[account(
+        seeds = [
+            MEMBERSHIP_SEED,
+            channel_id.to_le_bytes().as_ref(),
+            author.key().as_ref()
+        ],
+        bump,
+        constraint = membership.joined @ ErrorCode::NotChannelMember
+    )]
+    pub membership: Account<'info, Membership>,
+
+    pub author: Signer<'info>,
+}
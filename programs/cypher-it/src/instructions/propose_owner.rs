@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::constants::STATE_SEED;
+use crate::errors::ErrorCode;
+use crate::states::Config;
+
+// `None` cancels a pending transfer
+pub fn propose_owner(ctx: Context<ProposeOwnerCtx>, new_owner: Option<Pubkey>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let owner = &ctx.accounts.owner;
+
+    if config.owner != owner.key() {
+        return Err(ErrorCode::UnauthorizedOwner.into());
+    }
+
+    config.pending_owner = new_owner;
+
+    emit!(OwnerProposed {
+        owner: owner.key(),
+        pending_owner: new_owner,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ProposeOwnerCtx<'info> {
+    #[account(
+        mut,
+        seeds = [STATE_SEED],
+        bump,
+        constraint = config.owner == owner.key() @ ErrorCode::UnauthorizedOwner
+    )]
+    pub config: Account<'info, Config>,
+
+    pub owner: Signer<'info>,
+}
+
+#[event]
+pub struct OwnerProposed {
+    pub owner: Pubkey,
+    pub pending_owner: Option<Pubkey>,
+}
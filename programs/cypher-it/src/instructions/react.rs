@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use crate::constants::{MAX_EMOJI_LENGTH, MEMBERSHIP_SEED, MESSAGE_REACTIONS_SEED, PAUSE_GENERAL, STATE_SEED};
+use crate::errors::ErrorCode;
+use crate::states::{Config, Membership, MessageReactions};
+
+pub fn react(
+    ctx: Context<ReactCtx>,
+    channel_id: u64,
+    message_hash: [u8; 32],
+    emoji: String,
+) -> Result<()> {
+    let message_reactions = &mut ctx.accounts.message_reactions;
+    let member = &ctx.accounts.member;
+
+    if emoji.is_empty() || emoji.len() > MAX_EMOJI_LENGTH {
+        return Err(ErrorCode::InvalidEmoji.into());
+    }
+
+    let added = message_reactions.toggle(member.key(), emoji)?;
+
+    msg!(
+        "Reaction {} on message {:?} in channel {}",
+        if added { "added" } else { "removed" },
+        &message_hash[..4],
+        channel_id
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(channel_id: u64, message_hash: [u8; 32])]
+pub struct ReactCtx<'info> {
+    #[account(
+        seeds = [STATE_SEED],
+        bump,
+        constraint = !config.is_paused(PAUSE_GENERAL) @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [
+            MEMBERSHIP_SEED,
+            channel_id.to_le_bytes().as_ref(),
+            member.key().as_ref()
+        ],
+        bump,
+        constraint = membership.joined @ ErrorCode::NotChannelMember
+    )]
+    pub membership: Account<'info, Membership>,
+
+    #[account(
+        mut,
+        seeds = [
+            MESSAGE_REACTIONS_SEED,
+            channel_id.to_le_bytes().as_ref(),
+            message_hash.as_ref()
+        ],
+        bump = message_reactions.bump
+    )]
+    pub message_reactions: Account<'info, MessageReactions>,
+
+    pub member: Signer<'info>,
+}
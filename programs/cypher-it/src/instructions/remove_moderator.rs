@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::constants::{CHANNEL_SEED, PAUSE_GENERAL, STATE_SEED};
+use crate::errors::ErrorCode;
+use crate::states::{Channel, Config};
+
+pub fn remove_moderator(ctx: Context<RemoveModeratorCtx>, channel_id: u64, moderator: Pubkey) -> Result<()> {
+    let channel = &mut ctx.accounts.channel;
+    let creator = &ctx.accounts.creator;
+
+    let position = channel
+        .moderators
+        .iter()
+        .position(|m| *m == moderator)
+        .ok_or(ErrorCode::ModeratorNotFound)?;
+
+    channel.moderators.remove(position);
+    channel.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(ModeratorRemoved {
+        channel_id,
+        creator: creator.key(),
+        moderator,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(channel_id: u64)]
+pub struct RemoveModeratorCtx<'info> {
+    #[account(
+        seeds = [STATE_SEED],
+        bump,
+        constraint = !config.is_paused(PAUSE_GENERAL) @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [
+            CHANNEL_SEED,
+            channel_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        constraint = channel.creator == creator.key() @ ErrorCode::UnauthorizedChannelUpdate
+    )]
+    pub channel: Account<'info, Channel>,
+
+    pub creator: Signer<'info>,
+}
+
+#[event]
+pub struct ModeratorRemoved {
+    pub channel_id: u64,
+    pub creator: Pubkey,
+    pub moderator: Pubkey,
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use crate::constants::{ANCHOR_DISCRIMINATOR_SIZE, PAUSE_GENERAL, PROFILE_SEED, STATE_SEED, USERNAME_SEED};
+use crate::errors::ErrorCode;
+use crate::states::*;
+use crate::utils::{create_pda_account, normalize_username, username_seed, validate_display_name};
+
+#[derive(Accounts)]
+#[instruction(new_display_name: String)]
+pub struct RenameProfileCtx<'info> {
+    #[account(
+        seeds = [STATE_SEED],
+        bump,
+        constraint = !config.is_paused(PAUSE_GENERAL) @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [PROFILE_SEED, owner.key().as_ref()],
+        bump,
+        has_one = owner
+    )]
+    pub profile: Account<'info, Profile>,
+    // Released so others can claim it, unless the new name normalizes to the same handle
+    #[account(
+        mut,
+        seeds = [USERNAME_SEED, username_seed(&profile.display_name).as_ref()],
+        bump = old_username.bump,
+        has_one = owner
+    )]
+    pub old_username: Account<'info, Username>,
+    /// CHECK: Handle PDA for the new name - created in the handler when the handle changes
+    #[account(
+        mut,
+        seeds = [USERNAME_SEED, username_seed(&new_display_name).as_ref()],
+        bump
+    )]
+    pub new_username: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn rename_profile(ctx: Context<RenameProfileCtx>, new_display_name: String) -> Result<()> {
+    let profile = &mut ctx.accounts.profile;
+    let owner = &ctx.accounts.owner;
+
+    validate_display_name(&new_display_name)?;
+
+    // Case and whitespace fixes ("bob" -> "Bob") keep the handle they already hold
+    let new_seed = username_seed(&new_display_name);
+    if new_seed != username_seed(&profile.display_name) {
+        let new_username = ctx.accounts.new_username.to_account_info();
+        if !new_username.data_is_empty() {
+            return Err(ErrorCode::UsernameTaken.into());
+        }
+
+        create_pda_account(
+            &new_username,
+            &owner.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &[USERNAME_SEED, new_seed.as_ref(), &[ctx.bumps.new_username]],
+            ANCHOR_DISCRIMINATOR_SIZE + Username::INIT_SPACE,
+        )?;
+        let handle = Username {
+            owner: owner.key(),
+            name: normalize_username(&new_display_name),
+            bump: ctx.bumps.new_username,
+        };
+        handle.try_serialize(&mut &mut new_username.try_borrow_mut_data()?[..])?;
+
+        ctx.accounts.old_username.close(owner.to_account_info())?;
+    }
+
+    msg!("Profile renamed from {} to {}", profile.display_name, new_display_name);
+    profile.display_name = new_display_name;
+
+    Ok(())
+}
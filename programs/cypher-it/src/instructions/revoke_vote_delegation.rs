@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use crate::constants::{MEMBERSHIP_SEED, VOTE_DELEGATION_SEED};
+use crate::states::{Membership, VoteDelegation};
+
+pub fn revoke_vote_delegation(ctx: Context<RevokeVoteDelegationCtx>, channel_id: u64) -> Result<()> {
+    // A claimed seat closes the delegate's Membership; a rejoin then starts from zero anyway
+    let delegate_membership_info = &ctx.accounts.delegate_membership;
+    if delegate_membership_info.owner == &crate::ID && !delegate_membership_info.data_is_empty() {
+        let mut delegate_membership =
+            Membership::try_deserialize(&mut &delegate_membership_info.try_borrow_data()?[..])?;
+        delegate_membership.delegations_received = delegate_membership.delegations_received.saturating_sub(1);
+        delegate_membership.try_serialize(&mut &mut delegate_membership_info.try_borrow_mut_data()?[..])?;
+    }
+
+    emit!(VoteDelegationRevoked {
+        channel_id,
+        delegator: ctx.accounts.delegator.key(),
+        delegate: ctx.accounts.delegation.delegate,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(channel_id: u64)]
+pub struct RevokeVoteDelegationCtx<'info> {
+    #[account(
+        mut,
+        close = delegator,
+        seeds = [
+            VOTE_DELEGATION_SEED,
+            channel_id.to_le_bytes().as_ref(),
+            delegator.key().as_ref()
+        ],
+        bump = delegation.bump
+    )]
+    pub delegation: Account<'info, VoteDelegation>,
+
+    /// CHECK: The delegate's Membership, which may have been closed - decremented in the handler if it exists
+    #[account(
+        mut,
+        seeds = [
+            MEMBERSHIP_SEED,
+            channel_id.to_le_bytes().as_ref(),
+            delegation.delegate.as_ref()
+        ],
+        bump
+    )]
+    pub delegate_membership: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub delegator: Signer<'info>,
+}
+
+#[event]
+pub struct VoteDelegationRevoked {
+    pub channel_id: u64,
+    pub delegator: Pubkey,
+    pub delegate: Pubkey,
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use crate::constants::{CHANNEL_SEED, MAX_PLATFORM_FEE_BPS, STATE_SEED};
+use crate::errors::ErrorCode;
+use crate::states::{Channel, Config};
+
+// `None` returns the channel to the tiered schedule
+pub fn set_channel_fee_override(
+    ctx: Context<SetChannelFeeOverrideCtx>,
+    channel_id: u64,
+    fee_bps: Option<u64>,
+) -> Result<()> {
+    let channel = &mut ctx.accounts.channel;
+
+    if let Some(fee_bps) = fee_bps {
+        if fee_bps > MAX_PLATFORM_FEE_BPS {
+            return Err(ErrorCode::PlatformFeeExceedsMaximum.into());
+        }
+    }
+
+    channel.fee_override_bps = fee_bps;
+
+    emit!(ChannelFeeOverrideSet {
+        channel_id,
+        owner: ctx.accounts.owner.key(),
+        fee_bps,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(channel_id: u64)]
+pub struct SetChannelFeeOverrideCtx<'info> {
+    #[account(
+        seeds = [STATE_SEED],
+        bump,
+        constraint = config.owner == owner.key() @ ErrorCode::UnauthorizedOwner
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [
+            CHANNEL_SEED,
+            channel_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub channel: Account<'info, Channel>,
+
+    pub owner: Signer<'info>,
+}
+
+#[event]
+pub struct ChannelFeeOverrideSet {
+    pub channel_id: u64,
+    pub owner: Pubkey,
+    pub fee_bps: Option<u64>,
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::constants::{FEE_SCHEDULE_SEED, STATE_SEED};
+use crate::errors::ErrorCode;
+use crate::states::{Config, FeeSchedule, FeeTier};
+use crate::utils::validate_fee_tiers;
+
+pub fn set_fee_tiers(ctx: Context<SetFeeTiersCtx>, tiers: Vec<FeeTier>) -> Result<()> {
+    let fee_schedule = &mut ctx.accounts.fee_schedule;
+
+    validate_fee_tiers(&tiers)?;
+
+    fee_schedule.tiers = tiers.clone();
+
+    emit!(FeeTiersUpdated {
+        owner: ctx.accounts.owner.key(),
+        tiers,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetFeeTiersCtx<'info> {
+    #[account(
+        seeds = [STATE_SEED],
+        bump,
+        constraint = config.owner == owner.key() @ ErrorCode::UnauthorizedOwner
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [FEE_SCHEDULE_SEED],
+        bump = fee_schedule.bump
+    )]
+    pub fee_schedule: Account<'info, FeeSchedule>,
+
+    pub owner: Signer<'info>,
+}
+
+#[event]
+pub struct FeeTiersUpdated {
+    pub owner: Pubkey,
+    pub tiers: Vec<FeeTier>,
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::constants::STATE_SEED;
+use crate::errors::ErrorCode;
+use crate::states::Config;
+
+pub fn set_guardian(ctx: Context<SetGuardianCtx>, new_guardian: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let owner = &ctx.accounts.owner;
+
+    // Only program owner can set the guardian
+    if config.owner != owner.key() {
+        return Err(ErrorCode::UnauthorizedOwner.into());
+    }
+
+    let old_guardian = config.guardian;
+    config.guardian = new_guardian;
+
+    emit!(GuardianUpdated {
+        owner: owner.key(),
+        old_guardian,
+        new_guardian,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetGuardianCtx<'info> {
+    #[account(
+        mut,
+        seeds = [STATE_SEED],
+        bump,
+        constraint = config.owner == owner.key() @ ErrorCode::UnauthorizedOwner
+    )]
+    pub config: Account<'info, Config>,
+
+    pub owner: Signer<'info>,
+}
+
+#[event]
+pub struct GuardianUpdated {
+    pub owner: Pubkey,
+    pub old_guardian: Pubkey,
+    pub new_guardian: Pubkey,
+}
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use crate::constants::{CHANNEL_SEED, MAX_TRANSFER_ROYALTY, PAUSE_GENERAL, STATE_SEED};
+use crate::errors::ErrorCode;
+use crate::states::{Channel, Config};
+
+pub fn set_membership_policy(
+    ctx: Context<SetMembershipPolicyCtx>,
+    channel_id: u64,
+    transferable: bool,
+    max_members: u64,
+    transfer_royalty: u64,
+) -> Result<()> {
+    let channel = &mut ctx.accounts.channel;
+    let creator = &ctx.accounts.creator;
+
+    // Soulbound NFTs are frozen, so they can never change hands
+    if transferable && channel.soulbound {
+        return Err(ErrorCode::InvalidMembershipPolicy.into());
+    }
+
+    if transfer_royalty > MAX_TRANSFER_ROYALTY {
+        return Err(ErrorCode::TransferRoyaltyExceedsMaximum.into());
+    }
+
+    channel.transferable = transferable;
+    channel.max_members = max_members;
+    channel.transfer_royalty = transfer_royalty;
+    channel.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(MembershipPolicyUpdated {
+        channel_id,
+        creator: creator.key(),
+        transferable,
+        max_members,
+        transfer_royalty,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(channel_id: u64)]
+pub struct SetMembershipPolicyCtx<'info> {
+    #[account(
+        seeds = [STATE_SEED],
+        bump,
+        constraint = !config.is_paused(PAUSE_GENERAL) @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [
+            CHANNEL_SEED,
+            channel_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        constraint = channel.creator == creator.key() @ ErrorCode::UnauthorizedChannelUpdate
+    )]
+    pub channel: Account<'info, Channel>,
+
+    pub creator: Signer<'info>,
+}
+
+#[event]
+pub struct MembershipPolicyUpdated {
+    pub channel_id: u64,
+    pub creator: Pubkey,
+    pub transferable: bool,
+    pub max_members: u64,
+    pub transfer_royalty: u64,
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::constants::STATE_SEED;
+use crate::errors::ErrorCode;
+use crate::states::Config;
+
+pub fn set_paused(ctx: Context<SetPausedCtx>, paused: u8) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let authority = &ctx.accounts.authority;
+
+    if !config.is_guardian(&authority.key()) {
+        return Err(ErrorCode::Unauthorized.into());
+    }
+
+    if !Config::pause_bits_valid(paused) {
+        return Err(ErrorCode::InvalidPauseFlags.into());
+    }
+
+    // Only the owner can lift a pause, so a leaked guardian key can't undo one
+    let clears_bits = config.paused & !paused != 0;
+    if clears_bits && config.owner != authority.key() {
+        return Err(ErrorCode::UnauthorizedUnpause.into());
+    }
+
+    let old_paused = config.paused;
+    config.paused = paused;
+
+    emit!(PauseUpdated {
+        authority: authority.key(),
+        old_paused,
+        new_paused: paused,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetPausedCtx<'info> {
+    #[account(
+        mut,
+        seeds = [STATE_SEED],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+
+    pub authority: Signer<'info>,
+}
+
+#[event]
+pub struct PauseUpdated {
+    pub authority: Pubkey,
+    pub old_paused: u8,
+    pub new_paused: u8,
+}
@@ -1,10 +1,11 @@
 use anchor_lang::prelude::*;
-use crate::constants::{STATE_SEED, MAX_PLATFORM_FEE};
+use crate::constants::{BPS_PER_PERCENT, FEE_SCHEDULE_SEED, MAX_PLATFORM_FEE_BPS, STATE_SEED};
 use crate::errors::ErrorCode;
-use crate::states::Config;
+use crate::states::{Config, FeeSchedule};
 
-pub fn set_platform_fee(ctx: Context<SetPlatformFeeCtx>, new_fee: u64) -> Result<()> {
+pub fn set_platform_fee(ctx: Context<SetPlatformFeeCtx>, new_fee_bps: u64) -> Result<()> {
     let config = &mut ctx.accounts.config;
+    let fee_schedule = &mut ctx.accounts.fee_schedule;
     let owner = &ctx.accounts.owner;
 
     // Only program owner can set platform fee
@@ -13,17 +14,23 @@ pub fn set_platform_fee(ctx: Context<SetPlatformFeeCtx>, new_fee: u64) -> Result
     }
 
     // Validate fee is within acceptable range
-    if new_fee > MAX_PLATFORM_FEE {
+    if new_fee_bps > MAX_PLATFORM_FEE_BPS {
         return Err(ErrorCode::PlatformFeeExceedsMaximum.into());
     }
 
+    let old_fee_bps = fee_schedule.base_fee_bps;
+    fee_schedule.base_fee_bps = new_fee_bps;
+
+    // Keep the legacy percentage in step for create_fee_schedule and older readers
     let old_fee = config.platform_fee;
-    config.platform_fee = new_fee;
+    config.platform_fee = new_fee_bps / BPS_PER_PERCENT;
 
     emit!(PlatformFeeUpdated {
         owner: owner.key(),
         old_fee,
-        new_fee,
+        new_fee: config.platform_fee,
+        old_fee_bps,
+        new_fee_bps,
     });
 
     Ok(())
@@ -39,12 +46,21 @@ pub struct SetPlatformFeeCtx<'info> {
     )]
     pub config: Account<'info, Config>,
 
+    #[account(
+        mut,
+        seeds = [FEE_SCHEDULE_SEED],
+        bump = fee_schedule.bump
+    )]
+    pub fee_schedule: Account<'info, FeeSchedule>,
+
     pub owner: Signer<'info>,
 }
 
 #[event]
 pub struct PlatformFeeUpdated {
     pub owner: Pubkey,
-    pub old_fee: u64,
+    pub old_fee: u64, // Percentages, kept for existing consumers
     pub new_fee: u64,
-}
\ No newline at end of file
+    pub old_fee_bps: u64,
+    pub new_fee_bps: u64,
+}
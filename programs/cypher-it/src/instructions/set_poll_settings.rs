@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use crate::constants::{CHANNEL_SEED, MAX_POLL_DURATION, MIN_POLL_DURATION, PAUSE_POLLS, STATE_SEED};
+use crate::errors::ErrorCode;
+use crate::states::{Channel, Config};
+
+pub fn set_poll_settings(
+    ctx: Context<SetPollSettingsCtx>,
+    channel_id: u64,
+    min_poll_duration: i64,
+    max_poll_duration: i64,
+    max_open_polls: u16,
+    poll_cooldown: i64,
+    poll_deposit: u64,
+) -> Result<()> {
+    let channel = &mut ctx.accounts.channel;
+
+    if min_poll_duration < MIN_POLL_DURATION
+        || max_poll_duration > MAX_POLL_DURATION
+        || min_poll_duration > max_poll_duration
+        || max_open_polls == 0
+        || poll_cooldown < 0
+    {
+        return Err(ErrorCode::InvalidPollSettings.into());
+    }
+
+    channel.min_poll_duration = min_poll_duration;
+    channel.max_poll_duration = max_poll_duration;
+    channel.max_open_polls = max_open_polls;
+    channel.poll_cooldown = poll_cooldown;
+    channel.poll_deposit = poll_deposit;
+    channel.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(PollSettingsUpdated {
+        channel_id,
+        min_poll_duration,
+        max_poll_duration,
+        max_open_polls,
+        poll_cooldown,
+        poll_deposit,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(channel_id: u64)]
+pub struct SetPollSettingsCtx<'info> {
+    #[account(
+        seeds = [STATE_SEED],
+        bump,
+        constraint = !config.is_paused(PAUSE_POLLS) @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [
+            CHANNEL_SEED,
+            channel_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        constraint = channel.creator == creator.key() @ ErrorCode::UnauthorizedChannelUpdate
+    )]
+    pub channel: Account<'info, Channel>,
+
+    pub creator: Signer<'info>,
+}
+
+#[event]
+pub struct PollSettingsUpdated {
+    pub channel_id: u64,
+    pub min_poll_duration: i64,
+    pub max_poll_duration: i64,
+    pub max_open_polls: u16,
+    pub poll_cooldown: i64,
+    pub poll_deposit: u64,
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::constants::{CHANNEL_SEED, PAUSE_POLLS, PROTECTED_ROLES_ALL, STATE_SEED};
+use crate::errors::ErrorCode;
+use crate::states::{Channel, Config};
+
+pub fn set_protected_roles(ctx: Context<SetProtectedRolesCtx>, channel_id: u64, protected_roles: u8) -> Result<()> {
+    let channel = &mut ctx.accounts.channel;
+
+    if protected_roles & !PROTECTED_ROLES_ALL != 0 {
+        return Err(ErrorCode::InvalidProtectedRoles.into());
+    }
+
+    channel.protected_roles = protected_roles;
+    channel.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(ProtectedRolesUpdated {
+        channel_id,
+        protected_roles,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(channel_id: u64)]
+pub struct SetProtectedRolesCtx<'info> {
+    #[account(
+        seeds = [STATE_SEED],
+        bump,
+        constraint = !config.is_paused(PAUSE_POLLS) @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [
+            CHANNEL_SEED,
+            channel_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        constraint = channel.creator == creator.key() @ ErrorCode::UnauthorizedChannelUpdate
+    )]
+    pub channel: Account<'info, Channel>,
+
+    pub creator: Signer<'info>,
+}
+
+#[event]
+pub struct ProtectedRolesUpdated {
+    pub channel_id: u64,
+    pub protected_roles: u8,
+}
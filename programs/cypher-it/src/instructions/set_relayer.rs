@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::constants::{CHANNEL_SEED, MESSAGE_LOG_SEED, PAUSE_GENERAL, STATE_SEED};
+use crate::errors::ErrorCode;
+use crate::states::{Channel, Config, MessageLog};
+
+// Replaces a lost or compromised relayer key without touching committed batches
+pub fn set_relayer(ctx: Context<SetRelayerCtx>, channel_id: u64, relayer: Pubkey) -> Result<()> {
+    let message_log = &mut ctx.accounts.message_log;
+    let old_relayer = message_log.relayer;
+
+    message_log.relayer = relayer;
+    message_log.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(RelayerUpdated {
+        channel_id,
+        old_relayer,
+        new_relayer: relayer,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(channel_id: u64)]
+pub struct SetRelayerCtx<'info> {
+    #[account(
+        seeds = [STATE_SEED],
+        bump,
+        constraint = !config.is_paused(PAUSE_GENERAL) @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [
+            CHANNEL_SEED,
+            channel_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        constraint = channel.creator == creator.key() @ ErrorCode::UnauthorizedChannelUpdate
+    )]
+    pub channel: Account<'info, Channel>,
+
+    #[account(
+        mut,
+        seeds = [
+            MESSAGE_LOG_SEED,
+            channel_id.to_le_bytes().as_ref()
+        ],
+        bump = message_log.bump
+    )]
+    pub message_log: Account<'info, MessageLog>,
+
+    pub creator: Signer<'info>,
+}
+
+#[event]
+pub struct RelayerUpdated {
+    pub channel_id: u64,
+    pub old_relayer: Pubkey,
+    pub new_relayer: Pubkey,
+}
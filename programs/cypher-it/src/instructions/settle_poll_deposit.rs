@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use crate::constants::{CHANNEL_SEED, PAUSE_POLLS, STATE_SEED};
+use crate::errors::ErrorCode;
+use crate::states::{Channel, Config, Poll};
+
+#[derive(Accounts)]
+pub struct SettlePollDepositCtx<'info> {
+    // Anyone may crank an ended poll once it is back on the base layer
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = poll.ended @ ErrorCode::PollNotEnded
+    )]
+    pub poll: Account<'info, Poll>,
+
+    #[account(
+        mut,
+        seeds = [
+            CHANNEL_SEED,
+            poll.channel_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub channel: Account<'info, Channel>,
+
+    /// CHECK: Poll creator, receives the refund - validated against poll.creator
+    #[account(
+        mut,
+        constraint = creator.key() == poll.creator @ ErrorCode::Unauthorized
+    )]
+    pub creator: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump,
+        constraint = !config.is_paused(PAUSE_POLLS) @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+}
+
+// Refunds the deposit if most of the channel voted, otherwise slashes it to the channel vault, and frees the open-poll slot
+pub fn settle_poll_deposit(ctx: Context<SettlePollDepositCtx>) -> Result<()> {
+    let poll = &mut ctx.accounts.poll;
+    let channel = &mut ctx.accounts.channel;
+
+    require!(!poll.deposit_settled, ErrorCode::PollDepositAlreadySettled);
+
+    // Not required_votes: normal polls have none, so the creator's own vote would refund them
+    let refunded = poll.reached_member_quorum();
+    let deposit = poll.deposit;
+
+    if deposit > 0 {
+        let recipient = if refunded {
+            ctx.accounts.creator.to_account_info()
+        } else {
+            channel.to_account_info()
+        };
+
+        let poll_info = poll.to_account_info();
+        **poll_info.try_borrow_mut_lamports()? = poll_info
+            .lamports()
+            .checked_sub(deposit)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        **recipient.try_borrow_mut_lamports()? = recipient
+            .lamports()
+            .checked_add(deposit)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+    }
+
+    poll.deposit_settled = true;
+    channel.open_polls = channel.open_polls.saturating_sub(1);
+
+    emit!(PollDepositSettled {
+        poll: poll.key(),
+        channel_id: poll.channel_id,
+        creator: poll.creator,
+        deposit,
+        refunded,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct PollDepositSettled {
+    pub poll: Pubkey,
+    pub channel_id: u64,
+    pub creator: Pubkey,
+    pub deposit: u64,
+    pub refunded: bool,
+}
@@ -0,0 +1,81 @@
+// instructions/settle_poll_reputation.rs
+use anchor_lang::prelude::*;
+use crate::constants::{
+    PAUSE_POLLS,
+    POLL_TYPE_KICK,
+    PROFILE_SEED,
+    REPUTATION_KICK_PENALTY,
+    REPUTATION_MUTE_PENALTY,
+    REPUTATION_PER_QUORUM_POLL,
+    STATE_SEED,
+};
+use crate::errors::ErrorCode;
+use crate::states::{Config, Poll, Profile};
+
+#[derive(Accounts)]
+pub struct SettlePollReputationCtx<'info> {
+    // Anyone may crank an ended poll once it is back on the base layer
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = poll.ended @ ErrorCode::PollNotEnded
+    )]
+    pub poll: Account<'info, Poll>,
+
+    #[account(
+        mut,
+        seeds = [PROFILE_SEED, poll.creator.as_ref()],
+        bump
+    )]
+    pub creator_profile: Account<'info, Profile>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump,
+        constraint = !config.is_paused(PAUSE_POLLS) @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    // Required when a kick/mute poll passed
+    #[account(
+        mut,
+        constraint = poll.target == Some(target_profile.owner) @ ErrorCode::InvalidTarget
+    )]
+    pub target_profile: Option<Account<'info, Profile>>,
+}
+
+pub fn settle_poll_reputation(ctx: Context<SettlePollReputationCtx>) -> Result<()> {
+    let poll = &mut ctx.accounts.poll;
+    let creator_profile = &mut ctx.accounts.creator_profile;
+
+    require!(!poll.reputation_settled, ErrorCode::PollReputationAlreadySettled);
+
+    // Reward creators whose polls drew most of the channel, so a member can't farm
+    // reputation by voting on their own polls
+    if poll.reached_member_quorum() {
+        creator_profile.reputation = creator_profile
+            .reputation
+            .saturating_add(REPUTATION_PER_QUORUM_POLL);
+    }
+
+    // Penalize members who were successfully kicked or muted
+    if poll.passed {
+        let target_profile = ctx
+            .accounts
+            .target_profile
+            .as_mut()
+            .ok_or(ErrorCode::MissingTarget)?;
+        let penalty = if poll.poll_type == POLL_TYPE_KICK {
+            REPUTATION_KICK_PENALTY
+        } else {
+            REPUTATION_MUTE_PENALTY
+        };
+        target_profile.reputation = target_profile.reputation.saturating_sub(penalty);
+    }
+
+    poll.reputation_settled = true;
+
+    msg!("Reputation settled for poll in channel: {}", poll.channel_id);
+    Ok(())
+}
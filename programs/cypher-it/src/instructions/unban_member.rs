@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use crate::constants::{BAN_SEED, CHANNEL_SEED, PAUSE_GENERAL, STATE_SEED};
+use crate::errors::ErrorCode;
+use crate::states::{Ban, Channel, Config};
+
+pub fn unban_member(ctx: Context<UnbanMemberCtx>, channel_id: u64, wallet: Pubkey) -> Result<()> {
+    let channel = &ctx.accounts.channel;
+    let moderator = &ctx.accounts.moderator;
+
+    if !channel.is_moderator(&moderator.key()) {
+        return Err(ErrorCode::UnauthorizedModerator.into());
+    }
+
+    emit!(MemberUnbanned {
+        channel_id,
+        wallet,
+        unbanned_by: moderator.key(),
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(channel_id: u64, wallet: Pubkey)]
+pub struct UnbanMemberCtx<'info> {
+    #[account(
+        seeds = [STATE_SEED],
+        bump,
+        constraint = !config.is_paused(PAUSE_GENERAL) @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [
+            CHANNEL_SEED,
+            channel_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub channel: Account<'info, Channel>,
+
+    #[account(
+        mut,
+        close = moderator,
+        seeds = [
+            BAN_SEED,
+            channel_id.to_le_bytes().as_ref(),
+            wallet.as_ref()
+        ],
+        bump = ban.bump
+    )]
+    pub ban: Account<'info, Ban>,
+
+    #[account(mut)]
+    pub moderator: Signer<'info>,
+}
+
+#[event]
+pub struct MemberUnbanned {
+    pub channel_id: u64,
+    pub wallet: Pubkey,
+    pub unbanned_by: Pubkey,
+}
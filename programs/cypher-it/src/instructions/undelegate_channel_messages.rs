@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::constants::CHANNEL_SEED;
+use crate::errors::ErrorCode;
+use crate::states::{Channel, ChannelMessages};
+use ephemeral_rollups_sdk::anchor::commit;
+use ephemeral_rollups_sdk::ephem::commit_and_undelegate_accounts;
+
+#[commit]
+#[derive(Accounts)]
+pub struct UndelegateChannelMessagesCtx<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [CHANNEL_SEED, channel_messages.channel_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = channel.creator == payer.key() @ ErrorCode::UnauthorizedUndelegation
+    )]
+    pub channel: Account<'info, Channel>,
+
+    #[account(mut)]
+    pub channel_messages: Account<'info, ChannelMessages>,
+}
+
+impl<'info> UndelegateChannelMessagesCtx<'info> {
+    pub fn undelegate_channel_messages(&self) -> Result<()> {
+        commit_and_undelegate_accounts(
+            &self.payer,
+            vec![&self.channel_messages.to_account_info()],
+            &self.magic_context,
+            &self.magic_program,
+        )?;
+
+        msg!("Channel messages undelegated and committed");
+        Ok(())
+    }
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::constants::CHANNEL_SEED;
+use crate::errors::ErrorCode;
+use crate::states::{Channel, ChannelPins};
+use ephemeral_rollups_sdk::anchor::commit;
+use ephemeral_rollups_sdk::ephem::commit_and_undelegate_accounts;
+
+#[commit]
+#[derive(Accounts)]
+pub struct UndelegateChannelPinsCtx<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [CHANNEL_SEED, channel_pins.channel_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = channel.is_moderator(&payer.key()) @ ErrorCode::UnauthorizedUndelegation
+    )]
+    pub channel: Account<'info, Channel>,
+
+    #[account(mut)]
+    pub channel_pins: Account<'info, ChannelPins>,
+}
+
+impl<'info> UndelegateChannelPinsCtx<'info> {
+    pub fn undelegate_channel_pins(&self) -> Result<()> {
+        commit_and_undelegate_accounts(
+            &self.payer,
+            vec![&self.channel_pins.to_account_info()],
+            &self.magic_context,
+            &self.magic_program,
+        )?;
+
+        msg!("Channel pins undelegated and committed");
+        Ok(())
+    }
+}
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+use crate::states::MemberStats;
+use ephemeral_rollups_sdk::anchor::commit;
+use ephemeral_rollups_sdk::ephem::commit_and_undelegate_accounts;
+
+#[commit]
+#[derive(Accounts)]
+pub struct UndelegateMemberStatsCtx<'info> {
+    // Anyone may bring stats back, e.g. so a passed mute can be applied
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(mut)]
+    pub member_stats: Account<'info, MemberStats>,
+}
+
+impl<'info> UndelegateMemberStatsCtx<'info> {
+    pub fn undelegate_member_stats(&self) -> Result<()> {
+        commit_and_undelegate_accounts(
+            &self.payer,
+            vec![&self.member_stats.to_account_info()],
+            &self.magic_context,
+            &self.magic_program,
+        )?;
+
+        msg!("Member stats undelegated and committed");
+        Ok(())
+    }
+}
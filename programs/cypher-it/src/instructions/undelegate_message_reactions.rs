@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::constants::CHANNEL_SEED;
+use crate::errors::ErrorCode;
+use crate::states::{Channel, MessageReactions};
+use ephemeral_rollups_sdk::anchor::commit;
+use ephemeral_rollups_sdk::ephem::commit_and_undelegate_accounts;
+
+#[commit]
+#[derive(Accounts)]
+pub struct UndelegateMessageReactionsCtx<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [CHANNEL_SEED, message_reactions.channel_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = channel.is_moderator(&payer.key()) @ ErrorCode::UnauthorizedUndelegation
+    )]
+    pub channel: Account<'info, Channel>,
+
+    #[account(mut)]
+    pub message_reactions: Account<'info, MessageReactions>,
+}
+
+impl<'info> UndelegateMessageReactionsCtx<'info> {
+    pub fn undelegate_message_reactions(&self) -> Result<()> {
+        commit_and_undelegate_accounts(
+            &self.payer,
+            vec![&self.message_reactions.to_account_info()],
+            &self.magic_context,
+            &self.magic_program,
+        )?;
+
+        msg!("Message reactions undelegated and committed");
+        Ok(())
+    }
+}
@@ -1,6 +1,8 @@
 // instructions/undelegate_poll.rs
 use anchor_lang::prelude::*;
-use crate::states::Poll;
+use crate::constants::CHANNEL_SEED;
+use crate::errors::ErrorCode;
+use crate::states::{Channel, Poll};
 use ephemeral_rollups_sdk::anchor::commit;
 use ephemeral_rollups_sdk::ephem::commit_and_undelegate_accounts;
 
@@ -9,6 +11,12 @@ use ephemeral_rollups_sdk::ephem::commit_and_undelegate_accounts;
 pub struct UndelegatePollCtx<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [CHANNEL_SEED, poll.channel_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub channel: Account<'info, Channel>,
     
     #[account(mut)]
     pub poll: Account<'info, Poll>,
@@ -16,6 +24,16 @@ pub struct UndelegatePollCtx<'info> {
 
 impl<'info> UndelegatePollCtx<'info> {
     pub fn undelegate_poll(&self) -> Result<()> {
+        let clock = Clock::get()?;
+
+        // Mid-vote, only the channel creator can pull the poll out of the rollup
+        require!(
+            self.poll.ended
+                || self.poll.is_expired(clock.unix_timestamp)
+                || self.channel.creator == self.payer.key(),
+            ErrorCode::UnauthorizedUndelegation
+        );
+
         commit_and_undelegate_accounts(
             &self.payer,
             vec![&self.poll.to_account_info()],
@@ -26,4 +44,4 @@ impl<'info> UndelegatePollCtx<'info> {
         msg!("Poll undelegated and committed");
         Ok(())
     }
-}
\ No newline at end of file
+}
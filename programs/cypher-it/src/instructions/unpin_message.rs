@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::constants::{CHANNEL_PINS_SEED, CHANNEL_SEED, PAUSE_GENERAL, STATE_SEED};
+use crate::errors::ErrorCode;
+use crate::states::{Channel, ChannelPins, Config};
+
+pub fn unpin_message(ctx: Context<UnpinMessageCtx>, channel_id: u64, message_hash: [u8; 32]) -> Result<()> {
+    let channel_pins = &mut ctx.accounts.channel_pins;
+
+    let position = channel_pins
+        .pins
+        .iter()
+        .position(|pin| *pin == message_hash)
+        .ok_or(ErrorCode::MessageNotPinned)?;
+
+    channel_pins.pins.remove(position);
+
+    msg!("Message unpinned in channel: {}", channel_id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(channel_id: u64)]
+pub struct UnpinMessageCtx<'info> {
+    #[account(
+        seeds = [STATE_SEED],
+        bump,
+        constraint = !config.is_paused(PAUSE_GENERAL) @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [
+            CHANNEL_SEED,
+            channel_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        constraint = channel.is_moderator(&moderator.key()) @ ErrorCode::UnauthorizedModerator
+    )]
+    pub channel: Account<'info, Channel>,
+
+    #[account(
+        mut,
+        seeds = [
+            CHANNEL_PINS_SEED,
+            channel_id.to_le_bytes().as_ref()
+        ],
+        bump = channel_pins.bump
+    )]
+    pub channel_pins: Account<'info, ChannelPins>,
+
+    pub moderator: Signer<'info>,
+}
@@ -3,11 +3,15 @@ use crate::constants::{
     CHANNEL_SEED,
     MAX_CHANNEL_NAME_LENGTH,
     MAX_CHANNEL_DESCRIPTION_LENGTH,
-    MIN_CHANNEL_COST
+    MIN_CHANNEL_COST,
+    MAX_NFT_URI_LENGTH,
+    PAUSE_GENERAL,
+    STATE_SEED
 };
 use crate::errors::ErrorCode;
-use crate::states::Channel;
+use crate::states::{Channel, Config};
 
+#[allow(clippy::too_many_arguments)]
 pub fn update_channel(
     ctx: Context<UpdateChannelCtx>,
     channel_id: u64,
@@ -15,6 +19,8 @@ pub fn update_channel(
     description: Option<String>,
     cost: Option<u64>,
     is_private: Option<bool>,
+    min_poll_reputation: Option<u64>,
+    img_url: Option<String>,
 ) -> Result<()> {
     let channel = &mut ctx.accounts.channel;
     let creator = &ctx.accounts.creator;
@@ -64,6 +70,21 @@ pub fn update_channel(
         updated_fields.push(format!("is_private: {}", new_is_private));
     }
 
+    // Update poll reputation floor if provided
+    if let Some(new_min_poll_reputation) = min_poll_reputation {
+        channel.min_poll_reputation = new_min_poll_reputation;
+        updated_fields.push(format!("min_poll_reputation: {}", new_min_poll_reputation));
+    }
+
+    // Update image if provided; it becomes the URI of NFTs minted from now on
+    if let Some(new_img_url) = img_url {
+        if new_img_url.len() > MAX_NFT_URI_LENGTH {
+            return Err(ErrorCode::ImageUrlTooLong.into());
+        }
+        channel.img_url = new_img_url.clone();
+        updated_fields.push(format!("img_url: {}", new_img_url));
+    }
+
     // Update timestamp
     channel.updated_at = Clock::get()?.unix_timestamp;
 
@@ -80,6 +101,13 @@ pub fn update_channel(
 #[derive(Accounts)]
 #[instruction(channel_id: u64)]
 pub struct UpdateChannelCtx<'info> {
+    #[account(
+        seeds = [STATE_SEED],
+        bump,
+        constraint = !config.is_paused(PAUSE_GENERAL) @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
     #[account(
         mut,
         seeds = [
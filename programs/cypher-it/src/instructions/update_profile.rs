@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::constants::{PAUSE_GENERAL, PROFILE_SEED, STATE_SEED};
+use crate::errors::ErrorCode;
+use crate::states::*;
+use crate::utils::{validate_avatar_uri, validate_bio};
+
+#[derive(Accounts)]
+pub struct UpdateProfileCtx<'info> {
+    #[account(
+        seeds = [STATE_SEED],
+        bump,
+        constraint = !config.is_paused(PAUSE_GENERAL) @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [PROFILE_SEED, owner.key().as_ref()],
+        bump,
+        has_one = owner
+    )]
+    pub profile: Account<'info, Profile>,
+}
+
+pub fn update_profile(
+    ctx: Context<UpdateProfileCtx>,
+    bio: Option<String>,
+    avatar_uri: Option<String>,
+) -> Result<()> {
+    let profile = &mut ctx.accounts.profile;
+
+    if let Some(new_bio) = bio {
+        validate_bio(&new_bio)?;
+        profile.bio = new_bio;
+    }
+
+    if let Some(new_avatar_uri) = avatar_uri {
+        validate_avatar_uri(&new_avatar_uri)?;
+        profile.avatar_uri = new_avatar_uri;
+    }
+
+    msg!("Profile updated for {}", profile.owner);
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use crate::constants::{BAN_SEED, MEMBERSHIP_SEED, PLATFORM_BAN_SEED};
+use crate::errors::ErrorCode;
+use crate::states::Membership;
+use crate::utils::ensure_not_banned;
+
+// Stable entry point for partner programs; see `cpi_helpers` for the client side
+pub fn verify_membership(ctx: Context<VerifyMembershipCtx>, channel_id: u64, member: Pubkey) -> Result<()> {
+    let membership = &ctx.accounts.membership;
+
+    if membership.channel_id != channel_id || membership.member != member {
+        return Err(ErrorCode::MembershipNotFound.into());
+    }
+
+    if !membership.joined {
+        return Err(ErrorCode::NotChannelMember.into());
+    }
+
+    let current_time = Clock::get()?.unix_timestamp;
+    if membership.is_muted(current_time) {
+        return Err(ErrorCode::MemberMuted.into());
+    }
+
+    ensure_not_banned(&ctx.accounts.platform_ban, current_time)?;
+    ensure_not_banned(&ctx.accounts.ban, current_time)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(channel_id: u64, member: Pubkey)]
+pub struct VerifyMembershipCtx<'info> {
+    #[account(
+        seeds = [
+            MEMBERSHIP_SEED,
+            channel_id.to_le_bytes().as_ref(),
+            member.as_ref()
+        ],
+        bump
+    )]
+    pub membership: Account<'info, Membership>,
+
+    /// CHECK: Ban PDA for this member, usually uninitialized - checked in ensure_not_banned
+    #[account(
+        seeds = [
+            BAN_SEED,
+            channel_id.to_le_bytes().as_ref(),
+            member.as_ref()
+        ],
+        bump
+    )]
+    pub ban: UncheckedAccount<'info>,
+
+    /// CHECK: Platform ban PDA for this member - checked in ensure_not_banned
+    #[account(
+        seeds = [PLATFORM_BAN_SEED, member.as_ref()],
+        bump
+    )]
+    pub platform_ban: UncheckedAccount<'info>,
+}
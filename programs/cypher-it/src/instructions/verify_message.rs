@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::constants::{MAX_MERKLE_PROOF_DEPTH, MESSAGE_BATCH_SEED};
+use crate::errors::ErrorCode;
+use crate::states::MessageBatch;
+use crate::utils::{message_leaf, verify_merkle_proof};
+
+pub fn verify_message(
+    ctx: Context<VerifyMessageCtx>,
+    channel_id: u64,
+    batch_index: u64,
+    sequence: u64,
+    message_hash: [u8; 32],
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let batch = &ctx.accounts.batch;
+
+    if proof.len() > MAX_MERKLE_PROOF_DEPTH {
+        return Err(ErrorCode::MerkleProofTooLong.into());
+    }
+
+    if !batch.contains(sequence) {
+        return Err(ErrorCode::MessageOutOfBatchRange.into());
+    }
+
+    let leaf = message_leaf(sequence, &message_hash);
+    if !verify_merkle_proof(&batch.merkle_root, leaf, &proof) {
+        return Err(ErrorCode::InvalidMerkleProof.into());
+    }
+
+    msg!(
+        "Message {} verified in batch {} of channel {}",
+        sequence,
+        batch_index,
+        channel_id
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(channel_id: u64, batch_index: u64)]
+pub struct VerifyMessageCtx<'info> {
+    #[account(
+        seeds = [
+            MESSAGE_BATCH_SEED,
+            channel_id.to_le_bytes().as_ref(),
+            batch_index.to_le_bytes().as_ref()
+        ],
+        bump = batch.bump
+    )]
+    pub batch: Account<'info, MessageBatch>,
+}
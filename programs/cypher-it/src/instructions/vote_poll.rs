@@ -1,6 +1,7 @@
 // instructions/vote_poll.rs
 use anchor_lang::prelude::*;
-use crate::states::{Poll, Membership, Channel};
+use crate::constants::{MEMBER_STATS_SEED, PAUSE_POLLS, REPUTATION_PER_VOTE, STATE_SEED};
+use crate::states::{Poll, Membership, MemberStats, Channel, Config, Profile, VoteDelegation};
 use crate::errors::ErrorCode;
 
 #[derive(Accounts)]
@@ -16,7 +17,7 @@ pub struct VotePollCtx<'info> {
     pub channel: Account<'info, Channel>,
 
     #[account(
-        seeds = [b"membership", voter.key().as_ref(), channel.key().as_ref()],
+        seeds = [b"membership", channel_id.to_le_bytes().as_ref(), voter.key().as_ref()],
         bump ,
     )]
     pub membership: Account<'info, Membership>,
@@ -24,6 +25,29 @@ pub struct VotePollCtx<'info> {
     #[account(mut)]
     pub poll: Account<'info, Poll>,
 
+    // Optional: only writable when voting on the base layer, not in the ER
+    #[account(
+        mut,
+        seeds = [b"profile", voter.key().as_ref()],
+        bump,
+    )]
+    pub profile: Option<Account<'info, Profile>>,
+
+    // Voting in the rollup needs it delegated alongside the poll (delegate_member_stats)
+    #[account(
+        mut,
+        seeds = [MEMBER_STATS_SEED, channel_id.to_le_bytes().as_ref(), voter.key().as_ref()],
+        bump = member_stats.bump,
+    )]
+    pub member_stats: Account<'info, MemberStats>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump,
+        constraint = !config.is_paused(PAUSE_POLLS) @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -38,19 +62,26 @@ pub fn vote_poll(
 
     // Verify membership
     require!(
-        ctx.accounts.membership.channel_id == channel_id,
+        ctx.accounts.membership.channel_id == channel_id && ctx.accounts.membership.joined,
         ErrorCode::NotMember
     );
 
+    // Only members who joined before the poll started may vote
+    require!(
+        ctx.accounts.membership.joined_at <= poll.start_time,
+        ErrorCode::JoinedAfterPollStart
+    );
+
     // Check if poll belongs to this channel
     require!(
         poll.channel_id == channel_id,
         ErrorCode::InvalidChannel
     );
 
-    // Check if user has already voted
+    // A delegator whose delegate already voted for them may still vote directly once
+    let overrides_delegate = poll.is_delegated_vote(&voter);
     require!(
-        !poll.has_voted(&voter),
+        overrides_delegate || !poll.has_voted(&voter),
         ErrorCode::AlreadyVoted
     );
 
@@ -66,8 +97,28 @@ pub fn vote_poll(
         ErrorCode::InvalidOption
     );
 
+    // Remaining accounts carry (VoteDelegation, delegator Membership) pairs delegated to the voter
+    let delegators = collect_delegators(ctx.remaining_accounts, poll, channel_id, voter)?;
+
     // Add vote
-    poll.add_vote(voter, option_index)?;
+    if overrides_delegate {
+        poll.move_delegated_vote(&voter, option_index)?;
+    } else {
+        poll.add_vote(voter, option_index)?;
+    }
+    poll.add_delegated_votes(voter, &delegators, option_index)?;
+
+    // Only moderation polls need a real majority, so only they reward voting, and
+    // never for the creator's own poll
+    if poll.is_moderation() && poll.creator != voter {
+        if let Some(profile) = &mut ctx.accounts.profile {
+            profile.reputation = profile.reputation.saturating_add(REPUTATION_PER_VOTE);
+        }
+    }
+
+    let member_stats = &mut ctx.accounts.member_stats;
+    member_stats.votes_cast = member_stats.votes_cast.saturating_add(1);
+    member_stats.touch(clock.unix_timestamp);
 
     msg!(
         "Vote recorded: User {} voted for option {} in poll",
@@ -76,4 +127,41 @@ pub fn vote_poll(
     );
 
     Ok(())
-}
\ No newline at end of file
+}
+
+// Delegators who already voted directly, or who weren't eligible when the poll started, are skipped
+fn collect_delegators(
+    remaining_accounts: &[AccountInfo],
+    poll: &Poll,
+    channel_id: u64,
+    voter: Pubkey,
+) -> Result<Vec<Pubkey>> {
+    let pairs = remaining_accounts.chunks_exact(2);
+    require!(pairs.remainder().is_empty(), ErrorCode::InvalidVoteDelegation);
+
+    let mut delegators: Vec<Pubkey> = Vec::new();
+    for pair in pairs {
+        let (delegation_info, membership_info) = (&pair[0], &pair[1]);
+        require!(
+            delegation_info.owner == &crate::ID && membership_info.owner == &crate::ID,
+            ErrorCode::InvalidVoteDelegation
+        );
+
+        let delegation = VoteDelegation::try_deserialize(&mut &delegation_info.try_borrow_data()?[..])?;
+        let membership = Membership::try_deserialize(&mut &membership_info.try_borrow_data()?[..])?;
+        require!(
+            delegation.channel_id == channel_id
+                && delegation.delegate == voter
+                && membership.channel_id == channel_id
+                && membership.member == delegation.delegator,
+            ErrorCode::InvalidVoteDelegation
+        );
+
+        let eligible = membership.joined && membership.joined_at <= poll.start_time;
+        if eligible && !poll.has_voted(&delegation.delegator) && !delegators.contains(&delegation.delegator) {
+            delegators.push(delegation.delegator);
+        }
+    }
+
+    Ok(delegators)
+}
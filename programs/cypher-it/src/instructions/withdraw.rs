@@ -1,11 +1,12 @@
 use anchor_lang::prelude::*;
-use crate::constants::STATE_SEED;
+use crate::constants::{PAUSE_WITHDRAWALS, STATE_SEED, TREASURY_SEED};
 use crate::errors::ErrorCode;
-use crate::states::Config;
+use crate::states::{Config, PlatformTreasury};
 
 pub fn withdraw(ctx: Context<WithdrawCtx>, amount: u64) -> Result<()> {
     let config = &ctx.accounts.config;
     let owner = &ctx.accounts.owner;
+    let treasury = &mut ctx.accounts.treasury;
     let recipient = &ctx.accounts.recipient;
 
     // Only program owner can withdraw
@@ -18,24 +19,33 @@ pub fn withdraw(ctx: Context<WithdrawCtx>, amount: u64) -> Result<()> {
         return Err(ErrorCode::InvalidWithdrawalAmount.into());
     }
 
-    let recipient_info = recipient.to_account_info();
-    let available_balance = recipient_info.lamports();
+    // The treasury must stay rent-exempt
+    let treasury_info = treasury.to_account_info();
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(treasury_info.data_len());
+    let available_balance = treasury_info
+        .lamports()
+        .saturating_sub(rent_exempt_minimum);
 
     if amount > available_balance {
-        return Err(ErrorCode::InvalidWithdrawalAmount.into());
+        return Err(ErrorCode::InsufficientTreasuryBalance.into());
     }
 
     // Perform withdrawal
-    **recipient_info.try_borrow_mut_lamports()? = available_balance
+    **treasury_info.try_borrow_mut_lamports()? = treasury_info
+        .lamports()
         .checked_sub(amount)
         .ok_or(ErrorCode::ArithmeticOverflow)?;
 
-    **owner.to_account_info().try_borrow_mut_lamports()? = owner
+    **recipient.to_account_info().try_borrow_mut_lamports()? = recipient
         .to_account_info()
         .lamports()
         .checked_add(amount)
         .ok_or(ErrorCode::ArithmeticOverflow)?;
 
+    treasury.total_withdrawn = treasury.total_withdrawn
+        .checked_add(amount)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
     emit!(Withdraw {
         owner: owner.key(),
         recipient: recipient.key(),
@@ -50,15 +60,25 @@ pub struct WithdrawCtx<'info> {
     #[account(
         seeds = [STATE_SEED],
         bump,
-        constraint = config.owner == owner.key() @ ErrorCode::UnauthorizedOwner
+        constraint = config.owner == owner.key() @ ErrorCode::UnauthorizedOwner,
+        constraint = !config.is_paused(PAUSE_WITHDRAWALS) @ ErrorCode::ProgramPaused
     )]
     pub config: Account<'info, Config>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, PlatformTreasury>,
+
     pub owner: Signer<'info>,
 
-    /// CHECK: Can be any account that holds lamports for withdrawal
-    #[account(mut)]
+    /// CHECK: Fee recipient - validated by config.fee_recipient
+    #[account(
+        mut,
+        constraint = recipient.key() == config.fee_recipient
+    )]
     pub recipient: UncheckedAccount<'info>,
 }
 
@@ -67,4 +87,4 @@ pub struct Withdraw {
     pub owner: Pubkey,
     pub recipient: Pubkey,
     pub amount: u64,
-}
\ No newline at end of file
+}
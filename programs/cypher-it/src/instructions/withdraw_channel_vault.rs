@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use crate::constants::{CHANNEL_SEED, PAUSE_WITHDRAWALS, STATE_SEED};
+use crate::errors::ErrorCode;
+use crate::states::{Channel, Config};
+
+// The channel vault is the channel account's balance above rent, funded by slashed poll deposits
+pub fn withdraw_channel_vault(ctx: Context<WithdrawChannelVaultCtx>, channel_id: u64, amount: u64) -> Result<()> {
+    let channel = &ctx.accounts.channel;
+    let creator = &ctx.accounts.creator;
+
+    if amount == 0 {
+        return Err(ErrorCode::InvalidWithdrawalAmount.into());
+    }
+
+    let channel_info = channel.to_account_info();
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(channel_info.data_len());
+    let available_balance = channel_info
+        .lamports()
+        .saturating_sub(rent_exempt_minimum);
+
+    if amount > available_balance {
+        return Err(ErrorCode::InsufficientChannelVaultBalance.into());
+    }
+
+    **channel_info.try_borrow_mut_lamports()? = channel_info
+        .lamports()
+        .checked_sub(amount)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    **creator.to_account_info().try_borrow_mut_lamports()? = creator
+        .to_account_info()
+        .lamports()
+        .checked_add(amount)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    emit!(ChannelVaultWithdrawn {
+        channel_id,
+        creator: creator.key(),
+        amount,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(channel_id: u64)]
+pub struct WithdrawChannelVaultCtx<'info> {
+    #[account(
+        mut,
+        seeds = [
+            CHANNEL_SEED,
+            channel_id.to_le_bytes().as_ref()
+        ],
+        bump,
+        constraint = channel.creator == creator.key() @ ErrorCode::UnauthorizedChannelUpdate
+    )]
+    pub channel: Account<'info, Channel>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump,
+        constraint = !config.is_paused(PAUSE_WITHDRAWALS) @ ErrorCode::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+}
+
+#[event]
+pub struct ChannelVaultWithdrawn {
+    pub channel_id: u64,
+    pub creator: Pubkey,
+    pub amount: u64,
+}
@@ -1,17 +1,24 @@
 #![allow(unexpected_cfgs)]
 #![allow(deprecated)]
+// Instruction handlers mirror their on-chain argument lists
+#![allow(clippy::too_many_arguments)]
 use anchor_lang::prelude::*;
+use ephemeral_rollups_sdk::anchor::ephemeral;
 
 pub mod constants;
 pub mod errors;
 pub mod instructions;
 pub mod states;
 pub mod utils;
+#[cfg(feature = "cpi")]
+pub mod cpi_helpers;
 
 use instructions::*;
+use states::FeeTier;
 
 declare_id!("2QZ6YQeJmAfg6iWe76amzgfEHZsGBUXtC41a4reCiqPC");
 
+#[ephemeral]
 #[program]
 pub mod discord_like_program {
     use super::*;
@@ -27,8 +34,9 @@ pub mod discord_like_program {
         cost: u64,
         is_private: bool,
         img_url: String,
+        soulbound: bool,
     ) -> Result<()> {
-        instructions::create_channel::create_channel(ctx, name, description, img_url, cost, is_private)
+        instructions::create_channel::create_channel(ctx, name, description, img_url, cost, is_private, soulbound)
     }
 
     pub fn join_channel(ctx: Context<JoinChannelCtx>, channel_id: u64) -> Result<()> {
@@ -42,12 +50,23 @@ pub mod discord_like_program {
         description: Option<String>,
         cost: Option<u64>,
         is_private: Option<bool>,
+        min_poll_reputation: Option<u64>,
+        img_url: Option<String>,
     ) -> Result<()> {
-        instructions::update_channel::update_channel(ctx, channel_id, name, description, cost, is_private)
+        instructions::update_channel::update_channel(
+            ctx,
+            channel_id,
+            name,
+            description,
+            cost,
+            is_private,
+            min_poll_reputation,
+            img_url,
+        )
     }
 
-    pub fn set_platform_fee(ctx: Context<SetPlatformFeeCtx>, new_fee: u64) -> Result<()> {
-        instructions::set_platform_fee::set_platform_fee(ctx, new_fee)
+    pub fn set_platform_fee(ctx: Context<SetPlatformFeeCtx>, new_fee_bps: u64) -> Result<()> {
+        instructions::set_platform_fee::set_platform_fee(ctx, new_fee_bps)
     }
 
     pub fn set_fee_recipient(ctx: Context<SetFeeRecipientCtx>, new_recipient: Pubkey) -> Result<()> {
@@ -58,10 +77,148 @@ pub mod discord_like_program {
         instructions::withdraw::withdraw(ctx, amount)
     }
 
+    pub fn create_treasury(ctx: Context<CreateTreasuryCtx>) -> Result<()> {
+        instructions::create_treasury::create_treasury(ctx)
+    }
+
+    pub fn create_fee_schedule(ctx: Context<CreateFeeScheduleCtx>) -> Result<()> {
+        instructions::create_fee_schedule::create_fee_schedule(ctx)
+    }
+
+    pub fn set_fee_tiers(ctx: Context<SetFeeTiersCtx>, tiers: Vec<FeeTier>) -> Result<()> {
+        instructions::set_fee_tiers::set_fee_tiers(ctx, tiers)
+    }
+
+    pub fn set_channel_fee_override(
+        ctx: Context<SetChannelFeeOverrideCtx>,
+        channel_id: u64,
+        fee_bps: Option<u64>,
+    ) -> Result<()> {
+        instructions::set_channel_fee_override::set_channel_fee_override(ctx, channel_id, fee_bps)
+    }
+
+    pub fn propose_owner(ctx: Context<ProposeOwnerCtx>, new_owner: Option<Pubkey>) -> Result<()> {
+        instructions::propose_owner::propose_owner(ctx, new_owner)
+    }
+
+    pub fn accept_owner(ctx: Context<AcceptOwnerCtx>) -> Result<()> {
+        instructions::accept_owner::accept_owner(ctx)
+    }
+
+    pub fn set_guardian(ctx: Context<SetGuardianCtx>, new_guardian: Pubkey) -> Result<()> {
+        instructions::set_guardian::set_guardian(ctx, new_guardian)
+    }
+
+    pub fn set_paused(ctx: Context<SetPausedCtx>, paused: u8) -> Result<()> {
+        instructions::set_paused::set_paused(ctx, paused)
+    }
+
+    pub fn migrate_config(ctx: Context<MigrateConfigCtx>) -> Result<()> {
+        instructions::migrate_config::migrate_config(ctx)
+    }
+
+    pub fn migrate_channel(ctx: Context<MigrateChannelCtx>, channel_id: u64) -> Result<()> {
+        instructions::migrate_channel::migrate_channel(ctx, channel_id)
+    }
+
+    pub fn migrate_membership(ctx: Context<MigrateMembershipCtx>, channel_id: u64, member: Pubkey) -> Result<()> {
+        instructions::migrate_membership::migrate_membership(ctx, channel_id, member)
+    }
+
+    pub fn migrate_poll(ctx: Context<MigratePollCtx>) -> Result<()> {
+        instructions::migrate_poll::migrate_poll(ctx)
+    }
+
+    pub fn migrate_profile(ctx: Context<MigrateProfileCtx>, owner: Pubkey) -> Result<()> {
+        instructions::migrate_profile::migrate_profile(ctx, owner)
+    }
+
+    pub fn ban_member(ctx: Context<BanMemberCtx>, channel_id: u64, wallet: Pubkey, duration: i64) -> Result<()> {
+        instructions::ban_member::ban_member(ctx, channel_id, wallet, duration)
+    }
+
+    pub fn unban_member(ctx: Context<UnbanMemberCtx>, channel_id: u64, wallet: Pubkey) -> Result<()> {
+        instructions::unban_member::unban_member(ctx, channel_id, wallet)
+    }
+
+    pub fn platform_ban(ctx: Context<PlatformBanCtx>, wallet: Pubkey, duration: i64) -> Result<()> {
+        instructions::platform_ban::platform_ban(ctx, wallet, duration)
+    }
+
+    pub fn lift_platform_ban(ctx: Context<LiftPlatformBanCtx>, wallet: Pubkey) -> Result<()> {
+        instructions::lift_platform_ban::lift_platform_ban(ctx, wallet)
+    }
+
+    pub fn set_poll_settings(
+        ctx: Context<SetPollSettingsCtx>,
+        channel_id: u64,
+        min_poll_duration: i64,
+        max_poll_duration: i64,
+        max_open_polls: u16,
+        poll_cooldown: i64,
+        poll_deposit: u64,
+    ) -> Result<()> {
+        instructions::set_poll_settings::set_poll_settings(
+            ctx,
+            channel_id,
+            min_poll_duration,
+            max_poll_duration,
+            max_open_polls,
+            poll_cooldown,
+            poll_deposit,
+        )
+    }
+
+    pub fn settle_poll_deposit(ctx: Context<SettlePollDepositCtx>) -> Result<()> {
+        instructions::settle_poll_deposit::settle_poll_deposit(ctx)
+    }
+
+    pub fn withdraw_channel_vault(ctx: Context<WithdrawChannelVaultCtx>, channel_id: u64, amount: u64) -> Result<()> {
+        instructions::withdraw_channel_vault::withdraw_channel_vault(ctx, channel_id, amount)
+    }
+
+    pub fn set_protected_roles(ctx: Context<SetProtectedRolesCtx>, channel_id: u64, protected_roles: u8) -> Result<()> {
+        instructions::set_protected_roles::set_protected_roles(ctx, channel_id, protected_roles)
+    }
+
+    pub fn delegate_vote(ctx: Context<DelegateVoteCtx>, channel_id: u64, delegate: Pubkey) -> Result<()> {
+        instructions::delegate_vote::delegate_vote(ctx, channel_id, delegate)
+    }
+
+    pub fn revoke_vote_delegation(ctx: Context<RevokeVoteDelegationCtx>, channel_id: u64) -> Result<()> {
+        instructions::revoke_vote_delegation::revoke_vote_delegation(ctx, channel_id)
+    }
+
     pub fn leave_channel(ctx: Context<LeaveChannelCtx>, channel_id: u64) -> Result<()> {
         instructions::leave_channel::leave_channel(ctx, channel_id)
     }
 
+    pub fn set_membership_policy(
+        ctx: Context<SetMembershipPolicyCtx>,
+        channel_id: u64,
+        transferable: bool,
+        max_members: u64,
+        transfer_royalty: u64,
+    ) -> Result<()> {
+        instructions::set_membership_policy::set_membership_policy(ctx, channel_id, transferable, max_members, transfer_royalty)
+    }
+
+    pub fn claim_membership(ctx: Context<ClaimMembershipCtx>, channel_id: u64) -> Result<()> {
+        instructions::claim_membership::claim_membership(ctx, channel_id)
+    }
+
+    pub fn verify_membership(
+        ctx: Context<VerifyMembershipCtx>,
+        channel_id: u64,
+        member: Pubkey,
+    ) -> Result<()> {
+        instructions::verify_membership::verify_membership(ctx, channel_id, member)
+    }
+
+    pub fn init_member_stats(ctx: Context<InitMemberStatsCtx>, channel_id: u64) -> Result<()> {
+        instructions::init_member_stats::init_member_stats(ctx, channel_id)
+    }
+
     pub fn delete_channel(ctx: Context<DeleteChannelCtx>, channel_id: u64) -> Result<()> {
         instructions::delete_channel::delete_channel(ctx, channel_id)
     }
@@ -75,8 +232,45 @@ pub mod discord_like_program {
         instructions::create_profile::create_profile(ctx, display_name, bio, avatar_uri)
     }
 
-    pub fn get_profile(ctx: Context<GetProfileCtx>) -> Result<()> {
-        instructions::get_profile::get_profile(ctx)
+    // View instructions, results are returned via set_return_data
+    pub fn get_profile(ctx: Context<GetProfileCtx>, user: Pubkey) -> Result<ProfileView> {
+        instructions::get_profile::get_profile(ctx, user)
+    }
+
+    pub fn get_channel(ctx: Context<GetChannelCtx>, channel_id: u64) -> Result<ChannelView> {
+        instructions::get_channel::get_channel(ctx, channel_id)
+    }
+
+    pub fn get_membership_status(
+        ctx: Context<GetMembershipStatusCtx>,
+        channel_id: u64,
+        member: Pubkey,
+    ) -> Result<MembershipStatus> {
+        instructions::get_membership_status::get_membership_status(ctx, channel_id, member)
+    }
+
+    pub fn get_poll_results(ctx: Context<GetPollResultsCtx>) -> Result<PollResults> {
+        instructions::get_poll_results::get_poll_results(ctx)
+    }
+
+    pub fn update_profile(
+        ctx: Context<UpdateProfileCtx>,
+        bio: Option<String>,
+        avatar_uri: Option<String>,
+    ) -> Result<()> {
+        instructions::update_profile::update_profile(ctx, bio, avatar_uri)
+    }
+
+    pub fn rename_profile(ctx: Context<RenameProfileCtx>, new_display_name: String) -> Result<()> {
+        instructions::rename_profile::rename_profile(ctx, new_display_name)
+    }
+
+    pub fn close_profile(ctx: Context<CloseProfileCtx>) -> Result<()> {
+        instructions::close_profile::close_profile(ctx)
+    }
+
+    pub fn claim_username(ctx: Context<ClaimUsernameCtx>, display_name: String) -> Result<()> {
+        instructions::claim_username::claim_username(ctx, display_name)
     }
 
     // Poll instructions
@@ -114,4 +308,138 @@ pub mod discord_like_program {
     pub fn undelegate_poll(ctx: Context<UndelegatePollCtx>) -> Result<()> {
         ctx.accounts.undelegate_poll()
     }
+
+    pub fn finalize_poll(ctx: Context<FinalizePollCtx>) -> Result<()> {
+        instructions::finalize_poll::finalize_poll(ctx)
+    }
+
+    pub fn apply_poll_outcome(ctx: Context<ApplyPollOutcomeCtx>) -> Result<()> {
+        instructions::apply_poll_outcome::apply_poll_outcome(ctx)
+    }
+
+    pub fn settle_poll_reputation(ctx: Context<SettlePollReputationCtx>) -> Result<()> {
+        instructions::settle_poll_reputation::settle_poll_reputation(ctx)
+    }
+
+    // Message anchoring instructions
+    pub fn create_message_log(
+        ctx: Context<CreateMessageLogCtx>,
+        channel_id: u64,
+        relayer: Pubkey,
+    ) -> Result<()> {
+        instructions::create_message_log::create_message_log(ctx, channel_id, relayer)
+    }
+
+    pub fn set_relayer(
+        ctx: Context<SetRelayerCtx>,
+        channel_id: u64,
+        relayer: Pubkey,
+    ) -> Result<()> {
+        instructions::set_relayer::set_relayer(ctx, channel_id, relayer)
+    }
+
+    pub fn commit_messages(
+        ctx: Context<CommitMessagesCtx>,
+        channel_id: u64,
+        merkle_root: [u8; 32],
+        start_sequence: u64,
+        end_sequence: u64,
+    ) -> Result<()> {
+        instructions::commit_messages::commit_messages(ctx, channel_id, merkle_root, start_sequence, end_sequence)
+    }
+
+    pub fn verify_message(
+        ctx: Context<VerifyMessageCtx>,
+        channel_id: u64,
+        batch_index: u64,
+        sequence: u64,
+        message_hash: [u8; 32],
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::verify_message::verify_message(ctx, channel_id, batch_index, sequence, message_hash, proof)
+    }
+
+    // On-chain chat instructions
+    pub fn create_channel_messages(ctx: Context<CreateChannelMessagesCtx>, channel_id: u64) -> Result<()> {
+        instructions::create_channel_messages::create_channel_messages(ctx, channel_id)
+    }
+
+    pub fn delegate_channel_messages(ctx: Context<DelegateChannelMessagesCtx>, channel_id: u64) -> Result<()> {
+        instructions::delegate_channel_messages::delegate_channel_messages(ctx, channel_id)
+    }
+
+    pub fn undelegate_channel_messages(ctx: Context<UndelegateChannelMessagesCtx>) -> Result<()> {
+        ctx.accounts.undelegate_channel_messages()
+    }
+
+    pub fn post_message(ctx: Context<PostMessageCtx>, channel_id: u64, text: String) -> Result<()> {
+        instructions::post_message::post_message(ctx, channel_id, text)
+    }
+
+    // Moderator instructions
+    pub fn add_moderator(ctx: Context<AddModeratorCtx>, channel_id: u64, moderator: Pubkey) -> Result<()> {
+        instructions::add_moderator::add_moderator(ctx, channel_id, moderator)
+    }
+
+    pub fn remove_moderator(ctx: Context<RemoveModeratorCtx>, channel_id: u64, moderator: Pubkey) -> Result<()> {
+        instructions::remove_moderator::remove_moderator(ctx, channel_id, moderator)
+    }
+
+    // Pin and reaction instructions
+    pub fn create_channel_pins(ctx: Context<CreateChannelPinsCtx>, channel_id: u64) -> Result<()> {
+        instructions::create_channel_pins::create_channel_pins(ctx, channel_id)
+    }
+
+    pub fn delegate_channel_pins(ctx: Context<DelegateChannelPinsCtx>, channel_id: u64) -> Result<()> {
+        instructions::delegate_channel_pins::delegate_channel_pins(ctx, channel_id)
+    }
+
+    pub fn undelegate_channel_pins(ctx: Context<UndelegateChannelPinsCtx>) -> Result<()> {
+        ctx.accounts.undelegate_channel_pins()
+    }
+
+    pub fn pin_message(ctx: Context<PinMessageCtx>, channel_id: u64, message_hash: [u8; 32]) -> Result<()> {
+        instructions::pin_message::pin_message(ctx, channel_id, message_hash)
+    }
+
+    pub fn unpin_message(ctx: Context<UnpinMessageCtx>, channel_id: u64, message_hash: [u8; 32]) -> Result<()> {
+        instructions::unpin_message::unpin_message(ctx, channel_id, message_hash)
+    }
+
+    pub fn create_message_reactions(
+        ctx: Context<CreateMessageReactionsCtx>,
+        channel_id: u64,
+        message_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::create_message_reactions::create_message_reactions(ctx, channel_id, message_hash)
+    }
+
+    pub fn delegate_message_reactions(
+        ctx: Context<DelegateMessageReactionsCtx>,
+        channel_id: u64,
+        message_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::delegate_message_reactions::delegate_message_reactions(ctx, channel_id, message_hash)
+    }
+
+    pub fn undelegate_message_reactions(ctx: Context<UndelegateMessageReactionsCtx>) -> Result<()> {
+        ctx.accounts.undelegate_message_reactions()
+    }
+
+    pub fn delegate_member_stats(ctx: Context<DelegateMemberStatsCtx>, channel_id: u64) -> Result<()> {
+        instructions::delegate_member_stats::delegate_member_stats(ctx, channel_id)
+    }
+
+    pub fn undelegate_member_stats(ctx: Context<UndelegateMemberStatsCtx>) -> Result<()> {
+        ctx.accounts.undelegate_member_stats()
+    }
+
+    pub fn react(
+        ctx: Context<ReactCtx>,
+        channel_id: u64,
+        message_hash: [u8; 32],
+        emoji: String,
+    ) -> Result<()> {
+        instructions::react::react(ctx, channel_id, message_hash, emoji)
+    }
 }
\ No newline at end of file
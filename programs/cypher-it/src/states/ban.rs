@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Ban {
+    pub channel_id: u64, // 0 for platform-wide bans
+    pub wallet: Pubkey,
+    pub banned_by: Pubkey, // Moderator, program owner, or the kick poll that passed
+    pub created_at: i64,
+    pub expires_at: i64, // 0 = permanent
+    pub bump: u8,
+}
+
+impl Ban {
+    pub fn is_active(&self, current_time: i64) -> bool {
+        self.expires_at == 0 || current_time < self.expires_at
+    }
+}
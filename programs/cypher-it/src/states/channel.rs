@@ -1,5 +1,13 @@
 // states/channel.rs
 use anchor_lang::prelude::*;
+use crate::constants::{
+    CHANNEL_VERSION,
+    DEFAULT_MAX_OPEN_POLLS,
+    DEFAULT_MAX_POLL_DURATION,
+    DEFAULT_MIN_POLL_DURATION,
+    PROTECTED_MODERATORS,
+};
+use crate::states::{impl_versioned, ChannelV0};
 
 #[account]
 #[derive(InitSpace)]
@@ -18,4 +26,54 @@ pub struct Channel {
     #[max_len(256)]
     pub img_url: String,
     pub poll_count: u64, // Track number of polls created
+    #[max_len(5)]
+    pub moderators: Vec<Pubkey>,
+    pub min_poll_reputation: u64, // Required to create polls, 0 = anyone
+    pub soulbound: bool, // Membership NFTs are frozen and burned on leave/kick
+    pub transferable: bool, // Membership follows the NFT via claim_membership
+    pub max_members: u64, // 0 = unlimited
+    pub transfer_royalty: u64, // Percentage of cost paid to the creator on transfer
+    pub join_count: u64, // Total joins ever, numbers each membership NFT
+    pub fee_override_bps: Option<u64>, // Negotiated platform fee set by the owner
+    pub version: u8, // Layout version, upgraded by migrate_channel
+    pub min_poll_duration: i64, // Seconds, 0 = DEFAULT_MIN_POLL_DURATION
+    pub max_poll_duration: i64, // Seconds, 0 = DEFAULT_MAX_POLL_DURATION
+    pub poll_cooldown: i64, // Seconds between polls by the same member, 0 = none
+    pub poll_deposit: u64, // Lamports, refunded on quorum and slashed to the channel vault otherwise
+    pub max_open_polls: u16, // 0 = DEFAULT_MAX_OPEN_POLLS
+    pub open_polls: u16, // Polls whose deposit hasn't been settled yet
+    pub protected_roles: u8, // PROTECTED_* bits, roles kick/mute polls can't target
+    pub reserved: [u8; 27], // Room for new fields without a realloc
+}
+
+impl_versioned!(Channel, CHANNEL_VERSION, ChannelV0, upgrade_from);
+
+impl Channel {
+    // protected_roles arrived in version 3, so earlier channels read it as zero
+    // from their reserved bytes. Give them the same protection new channels get.
+    fn upgrade_from(&mut self, old_version: u8) {
+        if old_version < 3 {
+            self.protected_roles = PROTECTED_MODERATORS;
+        }
+    }
+
+    pub fn is_moderator(&self, user: &Pubkey) -> bool {
+        self.creator == *user || self.moderators.contains(user)
+    }
+
+    // The creator is always protected
+    pub fn is_protected(&self, user: &Pubkey) -> bool {
+        self.creator == *user
+            || (self.protected_roles & PROTECTED_MODERATORS != 0 && self.moderators.contains(user))
+    }
+
+    pub fn poll_duration_bounds(&self) -> (i64, i64) {
+        let min = if self.min_poll_duration > 0 { self.min_poll_duration } else { DEFAULT_MIN_POLL_DURATION };
+        let max = if self.max_poll_duration > 0 { self.max_poll_duration } else { DEFAULT_MAX_POLL_DURATION };
+        (min, max)
+    }
+
+    pub fn open_poll_limit(&self) -> u16 {
+        if self.max_open_polls > 0 { self.max_open_polls } else { DEFAULT_MAX_OPEN_POLLS }
+    }
 }
\ No newline at end of file
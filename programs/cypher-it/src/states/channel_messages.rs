@@ -0,0 +1,37 @@
+// states/channel_messages.rs
+use anchor_lang::prelude::*;
+use crate::constants::MESSAGE_RING_CAPACITY;
+use crate::errors::ErrorCode;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct ChatMessage {
+    pub author: Pubkey,
+    pub timestamp: i64,
+    #[max_len(280)]
+    pub text: String,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct ChannelMessages {
+    pub channel_id: u64,
+    pub total_posted: u64, // Monotonic; the ring slot is total_posted % capacity
+    #[max_len(24)]
+    pub messages: Vec<ChatMessage>,
+    pub bump: u8,
+}
+
+impl ChannelMessages {
+    pub fn push(&mut self, message: ChatMessage) -> Result<()> {
+        let slot = (self.total_posted % MESSAGE_RING_CAPACITY as u64) as usize;
+        if slot < self.messages.len() {
+            self.messages[slot] = message;
+        } else {
+            self.messages.push(message);
+        }
+        self.total_posted = self.total_posted
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,11 @@
+// states/channel_pins.rs
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct ChannelPins {
+    pub channel_id: u64,
+    #[max_len(10)]
+    pub pins: Vec<[u8; 32]>, // Message hashes, oldest first
+    pub bump: u8,
+}
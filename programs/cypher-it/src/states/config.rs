@@ -1,11 +1,34 @@
 use anchor_lang::prelude::*;
+use crate::constants::{CONFIG_VERSION, PAUSE_ALL};
+use crate::states::{impl_versioned, ConfigV0};
 
 #[account]
 #[derive(InitSpace)]
 pub struct Config {
     pub initialized: bool,
     pub total_channels: u64,
-    pub platform_fee: u64, // Percentage (0-50)
+    pub platform_fee: u64, // Legacy percentage, superseded by FeeSchedule
     pub fee_recipient: Pubkey,
     pub owner: Pubkey,
+    pub pending_owner: Option<Pubkey>, // Must call accept_owner to take over
+    pub guardian: Pubkey, // May pause but not unpause
+    pub paused: u8, // PAUSE_* bits
+    pub version: u8, // Layout version, upgraded by migrate_config
+    pub reserved: [u8; 64], // Room for new fields without a realloc
+}
+
+impl_versioned!(Config, CONFIG_VERSION, ConfigV0);
+
+impl Config {
+    pub fn is_paused(&self, subsystem: u8) -> bool {
+        self.paused & subsystem != 0
+    }
+
+    pub fn is_guardian(&self, user: &Pubkey) -> bool {
+        self.owner == *user || self.guardian == *user
+    }
+
+    pub fn pause_bits_valid(paused: u8) -> bool {
+        paused & !PAUSE_ALL == 0
+    }
 }
\ No newline at end of file
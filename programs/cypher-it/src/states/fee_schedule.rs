@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use crate::states::Channel;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct FeeTier {
+    pub min_cost: u64, // Tier applies to channels costing at least this, in lamports
+    pub fee_bps: u64,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct FeeSchedule {
+    pub base_fee_bps: u64, // Basis points (1/100 of a percent)
+    #[max_len(4)]
+    pub tiers: Vec<FeeTier>, // Sorted by ascending min_cost
+    pub bump: u8,
+}
+
+impl FeeSchedule {
+    // A per-channel override wins, then the highest cost band the channel reaches, then the base rate
+    pub fn fee_bps_for(&self, channel: &Channel) -> u64 {
+        if let Some(fee_bps) = channel.fee_override_bps {
+            return fee_bps;
+        }
+
+        self.tiers
+            .iter()
+            .rev()
+            .find(|tier| channel.cost >= tier.min_cost)
+            .map(|tier| tier.fee_bps)
+            .unwrap_or(self.base_fee_bps)
+    }
+}
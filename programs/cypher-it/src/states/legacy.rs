@@ -0,0 +1,196 @@
+// states/legacy.rs
+// Layouts written before accounts carried a version byte. migrate_account decodes
+// these explicitly, since bytes past their Borsh payload may be stale rather than zero.
+use anchor_lang::prelude::*;
+use crate::constants::PROTECTED_MODERATORS;
+use crate::states::{Channel, Config, Membership, Poll, Profile};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct ConfigV0 {
+    pub initialized: bool,
+    pub total_channels: u64,
+    pub platform_fee: u64,
+    pub fee_recipient: Pubkey,
+    pub owner: Pubkey,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct ChannelV0 {
+    pub id: u64,
+    #[max_len(64)]
+    pub name: String,
+    #[max_len(512)]
+    pub description: String,
+    pub cost: u64,
+    pub creator: Pubkey,
+    pub is_private: bool,
+    pub member_count: u64,
+    pub created_at: i64,
+    pub updated_at: i64,
+    #[max_len(256)]
+    pub img_url: String,
+    pub poll_count: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct MembershipV0 {
+    pub channel_id: u64,
+    pub member: Pubkey,
+    pub joined: bool,
+    pub joined_at: i64,
+    pub nft_mint: Option<Pubkey>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct PollV0 {
+    pub channel_id: u64,
+    pub creator: Pubkey,
+    #[max_len(200)]
+    pub poll_question: String,
+    pub poll_type: u8,
+    #[max_len(10, 100)]
+    pub options: Vec<String>,
+    #[max_len(10)]
+    pub votes: Vec<u64>,
+    pub total_votes: u64,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub required_votes: u64,
+    pub target: Option<Pubkey>,
+    #[max_len(100)]
+    pub voted: Vec<Pubkey>,
+    pub ended: bool,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct ProfileV0 {
+    pub owner: Pubkey,
+    #[max_len(50)]
+    pub display_name: String,
+    #[max_len(200)]
+    pub bio: String,
+    #[max_len(200)]
+    pub avatar_uri: String,
+    pub created_at: i64,
+}
+
+// The owner guarded the program alone before a guardian existed
+impl From<ConfigV0> for Config {
+    fn from(legacy: ConfigV0) -> Self {
+        Config {
+            initialized: legacy.initialized,
+            total_channels: legacy.total_channels,
+            platform_fee: legacy.platform_fee,
+            fee_recipient: legacy.fee_recipient,
+            owner: legacy.owner,
+            pending_owner: None,
+            guardian: legacy.owner,
+            paused: 0,
+            version: 0,
+            reserved: [0; 64],
+        }
+    }
+}
+
+// Gets the same protections as a new channel. Join numbers continue after the
+// current members, the closest count the old layout kept.
+impl From<ChannelV0> for Channel {
+    fn from(legacy: ChannelV0) -> Self {
+        Channel {
+            id: legacy.id,
+            name: legacy.name,
+            description: legacy.description,
+            cost: legacy.cost,
+            creator: legacy.creator,
+            is_private: legacy.is_private,
+            member_count: legacy.member_count,
+            created_at: legacy.created_at,
+            updated_at: legacy.updated_at,
+            img_url: legacy.img_url,
+            poll_count: legacy.poll_count,
+            moderators: Vec::new(),
+            min_poll_reputation: 0,
+            soulbound: false,
+            transferable: false,
+            max_members: 0,
+            transfer_royalty: 0,
+            join_count: legacy.member_count,
+            fee_override_bps: None,
+            version: 0,
+            min_poll_duration: 0,
+            max_poll_duration: 0,
+            poll_cooldown: 0,
+            poll_deposit: 0,
+            max_open_polls: 0,
+            open_polls: 0,
+            protected_roles: PROTECTED_MODERATORS,
+            reserved: [0; 27],
+        }
+    }
+}
+
+impl From<MembershipV0> for Membership {
+    fn from(legacy: MembershipV0) -> Self {
+        Membership {
+            channel_id: legacy.channel_id,
+            member: legacy.member,
+            joined: legacy.joined,
+            joined_at: legacy.joined_at,
+            nft_mint: legacy.nft_mint,
+            muted_until: 0,
+            version: 0,
+            last_poll_at: 0,
+            targeted_until: 0,
+            delegations_received: 0,
+            reserved: [0; 44],
+        }
+    }
+}
+
+// Old polls held no deposit and were never counted in the channel's open_polls,
+// so they start out settled
+impl From<PollV0> for Poll {
+    fn from(legacy: PollV0) -> Self {
+        Poll {
+            channel_id: legacy.channel_id,
+            creator: legacy.creator,
+            poll_question: legacy.poll_question,
+            poll_type: legacy.poll_type,
+            options: legacy.options,
+            votes: legacy.votes,
+            total_votes: legacy.total_votes,
+            start_time: legacy.start_time,
+            end_time: legacy.end_time,
+            required_votes: legacy.required_votes,
+            target: legacy.target,
+            voted: legacy.voted,
+            ended: legacy.ended,
+            passed: false,
+            executed: false,
+            reputation_settled: false,
+            bump: legacy.bump,
+            version: 0,
+            deposit: 0,
+            deposit_settled: true,
+            member_count_snapshot: 0,
+            delegated_votes: Vec::new(),
+            reserved: [0; 43],
+        }
+    }
+}
+
+impl From<ProfileV0> for Profile {
+    fn from(legacy: ProfileV0) -> Self {
+        Profile {
+            owner: legacy.owner,
+            display_name: legacy.display_name,
+            bio: legacy.bio,
+            avatar_uri: legacy.avatar_uri,
+            created_at: legacy.created_at,
+            reputation: 0,
+            version: 0,
+            reserved: [0; 64],
+        }
+    }
+}
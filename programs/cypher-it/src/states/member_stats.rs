@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct MemberStats {
+    pub channel_id: u64,
+    pub member: Pubkey,
+    pub polls_created: u64,
+    pub votes_cast: u64,
+    pub times_muted: u64,
+    pub total_paid: u64, // in lamports
+    pub last_active_at: i64,
+    pub bump: u8,
+}
+
+impl MemberStats {
+    pub fn touch(&mut self, current_time: i64) {
+        self.last_active_at = current_time;
+    }
+}
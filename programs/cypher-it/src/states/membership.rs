@@ -1,4 +1,6 @@
 use anchor_lang::prelude::*;
+use crate::constants::MEMBERSHIP_VERSION;
+use crate::states::{impl_versioned, MembershipV0};
 
 #[account]
 #[derive(InitSpace)]
@@ -8,4 +10,18 @@ pub struct Membership {
     pub joined: bool,
     pub joined_at: i64,
     pub nft_mint: Option<Pubkey>, // Optional NFT mint for membership
+    pub muted_until: i64, // Unix timestamp until which the member is muted
+    pub version: u8, // Layout version, upgraded by migrate_membership
+    pub last_poll_at: i64, // When this member last created a poll, for the channel's cooldown
+    pub targeted_until: i64, // End of the open kick/mute poll against this member, blocks duplicates
+    pub delegations_received: u32, // Live VoteDelegations naming this member, who then can't delegate onward
+    pub reserved: [u8; 44], // Room for new fields without a realloc
+}
+
+impl_versioned!(Membership, MEMBERSHIP_VERSION, MembershipV0);
+
+impl Membership {
+    pub fn is_muted(&self, current_time: i64) -> bool {
+        current_time < self.muted_until
+    }
 }
\ No newline at end of file
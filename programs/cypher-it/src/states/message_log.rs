@@ -0,0 +1,33 @@
+// states/message_log.rs
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct MessageLog {
+    pub channel_id: u64,
+    pub relayer: Pubkey,      // Off-chain server allowed to anchor batches
+    pub next_sequence: u64,   // First sequence number expected in the next batch
+    pub batch_count: u64,     // Track number of batches for PDA derivation
+    pub last_root: [u8; 32],
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct MessageBatch {
+    pub channel_id: u64,
+    pub index: u64,
+    pub merkle_root: [u8; 32],
+    pub start_sequence: u64,
+    pub end_sequence: u64,    // Inclusive
+    pub submitter: Pubkey,
+    pub committed_at: i64,
+    pub bump: u8,
+}
+
+impl MessageBatch {
+    pub fn contains(&self, sequence: u64) -> bool {
+        sequence >= self.start_sequence && sequence <= self.end_sequence
+    }
+}
@@ -0,0 +1,83 @@
+// states/message_reactions.rs
+use anchor_lang::prelude::*;
+use crate::constants::{MAX_REACTION_KINDS, MAX_REACTORS};
+use crate::errors::ErrorCode;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct ReactionCount {
+    #[max_len(16)]
+    pub emoji: String,
+    pub count: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct Reactor {
+    pub user: Pubkey,
+    pub emoji_index: u8, // Index into `reactions`
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct MessageReactions {
+    pub channel_id: u64,
+    pub message_hash: [u8; 32],
+    #[max_len(10)]
+    pub reactions: Vec<ReactionCount>,
+    #[max_len(64)]
+    pub reactors: Vec<Reactor>, // Prevents double reactions
+    pub bump: u8,
+}
+
+impl MessageReactions {
+    // Adds the user's reaction, or removes it if they already reacted with this emoji
+    pub fn toggle(&mut self, user: Pubkey, emoji: String) -> Result<bool> {
+        let emoji_index = match self.reactions.iter().position(|r| r.emoji == emoji) {
+            Some(index) => index,
+            None => {
+                require!(
+                    self.reactions.len() < MAX_REACTION_KINDS,
+                    ErrorCode::TooManyReactionKinds
+                );
+                self.reactions.push(ReactionCount { emoji, count: 0 });
+                self.reactions.len() - 1
+            }
+        };
+
+        let existing = self
+            .reactors
+            .iter()
+            .position(|r| r.user == user && r.emoji_index as usize == emoji_index);
+
+        let reaction = &mut self.reactions[emoji_index];
+        match existing {
+            Some(position) => {
+                self.reactors.swap_remove(position);
+                reaction.count = reaction.count
+                    .checked_sub(1)
+                    .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+                // Free the slot so a different emoji can take it
+                if reaction.count == 0 {
+                    self.reactions.remove(emoji_index);
+                    for reactor in self.reactors.iter_mut() {
+                        if reactor.emoji_index as usize > emoji_index {
+                            reactor.emoji_index -= 1;
+                        }
+                    }
+                }
+                Ok(false)
+            }
+            None => {
+                require!(self.reactors.len() < MAX_REACTORS, ErrorCode::TooManyReactions);
+                self.reactors.push(Reactor {
+                    user,
+                    emoji_index: emoji_index as u8,
+                });
+                reaction.count = reaction.count
+                    .checked_add(1)
+                    .ok_or(ErrorCode::ArithmeticOverflow)?;
+                Ok(true)
+            }
+        }
+    }
+}
@@ -1,12 +1,36 @@
 
+pub mod versioned;
+pub mod legacy;
 pub mod config;
+pub mod treasury;
+pub mod fee_schedule;
 pub mod channel;
 pub mod membership;
+pub mod member_stats;
 pub mod profile;
+pub mod username;
+pub mod ban;
+pub mod vote_delegation;
 pub mod poll;
+pub mod message_log;
+pub mod channel_messages;
+pub mod channel_pins;
+pub mod message_reactions;
 
+pub use versioned::*;
+pub use legacy::*;
 pub use config::*;
+pub use treasury::*;
+pub use fee_schedule::*;
 pub use channel::*;
 pub use membership::*;
+pub use member_stats::*;
 pub use profile::*;
-pub use poll::*;
\ No newline at end of file
+pub use username::*;
+pub use ban::*;
+pub use vote_delegation::*;
+pub use poll::*;
+pub use message_log::*;
+pub use channel_messages::*;
+pub use channel_pins::*;
+pub use message_reactions::*;
\ No newline at end of file
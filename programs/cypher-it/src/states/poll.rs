@@ -1,5 +1,23 @@
 // states/poll.rs
 use anchor_lang::prelude::*;
+use crate::constants::{
+    MAX_DELEGATED_VOTES,
+    MAX_POLL_VOTERS,
+    MIN_MEMBER_QUORUM,
+    POLL_TYPE_KICK,
+    POLL_TYPE_MUTE,
+    POLL_VERSION,
+};
+use crate::errors::ErrorCode;
+use crate::states::{impl_versioned, PollV0};
+
+// A vote counted through a VoteDelegation, moved if the delegator later votes directly
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct DelegatedVote {
+    pub delegator: Pubkey,
+    pub delegate: Pubkey,
+    pub option_index: u8,
+}
 
 #[account]
 #[derive(InitSpace)]
@@ -21,9 +39,21 @@ pub struct Poll {
     #[max_len(100)]
     pub voted: Vec<Pubkey>,
     pub ended: bool,
+    pub passed: bool,   // Outcome recorded when the poll is ended or finalized
+    pub executed: bool, // Kick/mute action has been applied on the base layer
+    pub reputation_settled: bool,
     pub bump: u8,
+    pub version: u8, // Layout version, upgraded by migrate_poll
+    pub deposit: u64, // Lamports held by the poll account until settle_poll_deposit
+    pub deposit_settled: bool,
+    pub member_count_snapshot: u64, // Channel size at creation, only members who joined by start_time may vote
+    #[max_len(50)]
+    pub delegated_votes: Vec<DelegatedVote>, // Its length prefix came out of reserved in version 4
+    pub reserved: [u8; 43], // Room for new fields without a realloc
 }
 
+impl_versioned!(Poll, POLL_VERSION, PollV0);
+
 impl Poll {
     pub fn has_voted(&self, user: &Pubkey) -> bool {
         self.voted.contains(user)
@@ -34,9 +64,92 @@ impl Poll {
     }
 
     pub fn add_vote(&mut self, user: Pubkey, option_index: u64) -> Result<()> {
+        require!(self.voted.len() < MAX_POLL_VOTERS, ErrorCode::PollVoterLimitReached);
         self.votes[option_index as usize] += 1;
         self.total_votes += 1;
         self.voted.push(user);
         Ok(())
     }
+
+    // Delegators are marked as voted so they can't be counted twice, and recorded so a
+    // later direct vote can move their weight
+    pub fn add_delegated_votes(
+        &mut self,
+        delegate: Pubkey,
+        delegators: &[Pubkey],
+        option_index: u64,
+    ) -> Result<()> {
+        if delegators.is_empty() {
+            return Ok(());
+        }
+        // Polls created before version 4 weren't allocated room for the records
+        require!(self.version >= 4, ErrorCode::PollNeedsMigration);
+        require!(
+            self.voted.len() + delegators.len() <= MAX_POLL_VOTERS,
+            ErrorCode::PollVoterLimitReached
+        );
+        require!(
+            self.delegated_votes.len() + delegators.len() <= MAX_DELEGATED_VOTES,
+            ErrorCode::DelegatedVoteLimitReached
+        );
+
+        for delegator in delegators {
+            self.add_vote(*delegator, option_index)?;
+            self.delegated_votes.push(DelegatedVote {
+                delegator: *delegator,
+                delegate,
+                option_index: option_index as u8,
+            });
+        }
+        Ok(())
+    }
+
+    pub fn is_delegated_vote(&self, user: &Pubkey) -> bool {
+        self.delegated_votes.iter().any(|vote| vote.delegator == *user)
+    }
+
+    // The delegator's own vote replaces the one their delegate cast for them
+    pub fn move_delegated_vote(&mut self, delegator: &Pubkey, option_index: u64) -> Result<()> {
+        let position = self.delegated_votes
+            .iter()
+            .position(|vote| vote.delegator == *delegator)
+            .ok_or(ErrorCode::AlreadyVoted)?;
+        let previous = self.delegated_votes.remove(position);
+
+        self.votes[previous.option_index as usize] -= 1;
+        self.votes[option_index as usize] += 1;
+        Ok(())
+    }
+
+    pub fn is_expired(&self, current_time: i64) -> bool {
+        current_time > self.end_time
+    }
+
+    pub fn is_moderation(&self) -> bool {
+        self.poll_type == POLL_TYPE_KICK || self.poll_type == POLL_TYPE_MUTE
+    }
+
+    // A majority of the members at poll start voted. Normal polls have no required
+    // votes, so this is what rewards and refunds key off instead.
+    pub fn reached_member_quorum(&self) -> bool {
+        let majority = self.member_count_snapshot / 2 + 1;
+        self.total_votes >= majority.max(MIN_MEMBER_QUORUM)
+    }
+
+    pub fn winning_option(&self) -> Option<u64> {
+        let max = *self.votes.iter().max()?;
+        let mut leaders = self.votes.iter().enumerate().filter(|(_, v)| **v == max);
+        match (leaders.next(), leaders.next()) {
+            (Some((index, _)), None) if max > 0 => Some(index as u64),
+            _ => None,
+        }
+    }
+
+    // "Yes" must beat "No" and reach the required number of votes
+    pub fn is_passed(&self) -> bool {
+        if !self.is_moderation() || self.votes.len() < 2 {
+            return false;
+        }
+        self.votes[0] > self.votes[1] && self.total_votes >= self.required_votes
+    }
 }
\ No newline at end of file
@@ -1,14 +1,21 @@
 use anchor_lang::prelude::*;
+use crate::constants::PROFILE_VERSION;
+use crate::states::{impl_versioned, ProfileV0};
 
 #[account]
+#[derive(InitSpace)]
 pub struct Profile {
     pub owner: Pubkey,         // user’s wallet
+    #[max_len(50)]
     pub display_name: String,  // nickname or username
+    #[max_len(200)]
     pub bio: String,           // short user bio
+    #[max_len(200)]
     pub avatar_uri: String,    // optional pfp or NFT image link
     pub created_at: i64,
+    pub reputation: u64,       // earned through governance participation
+    pub version: u8, // Layout version, upgraded by migrate_profile
+    pub reserved: [u8; 64], // Room for new fields without a realloc
 }
 
-impl Profile {
-    pub const MAX_SIZE: u64 = 32 + (4 + 50) + (4 + 200) + (4 + 200) + 8;
-}
+impl_versioned!(Profile, PROFILE_VERSION, ProfileV0);
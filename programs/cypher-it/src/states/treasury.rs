@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+
+#[account]
+#[derive(InitSpace)]
+pub struct PlatformTreasury {
+    pub total_collected: u64, // Lifetime platform fees, in lamports
+    pub total_withdrawn: u64,
+    pub bump: u8,
+}
+
+impl PlatformTreasury {
+    pub fn record_fee(&mut self, amount: u64) -> Result<()> {
+        self.total_collected = self.total_collected
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Username {
+    pub owner: Pubkey,
+    #[max_len(50)]
+    pub name: String, // Normalized lowercase handle
+    pub bump: u8,
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+// Implemented by accounts that can be upgraded in place by a migrate_* instruction
+pub trait Versioned: AccountSerialize + AccountDeserialize + Discriminator {
+    const CURRENT_VERSION: u8;
+
+    // Layout written before the account had a version byte, see states/legacy.rs
+    type Legacy: AnchorDeserialize + Space;
+
+    fn from_legacy(legacy: Self::Legacy) -> Self;
+
+    fn version(&self) -> u8;
+
+    fn set_version(&mut self, version: u8);
+
+    // Fills fields that older versions left as zeroed reserved bytes
+    fn upgrade_from(&mut self, _old_version: u8) {}
+}
+
+// The optional fourth argument names an inherent `fn(&mut self, u8)` used as upgrade_from
+macro_rules! impl_versioned {
+    ($account:ty, $current:expr, $legacy:ty, $upgrade:ident) => {
+        $crate::states::impl_versioned!(@impl $account, $current, $legacy, {
+            fn upgrade_from(&mut self, old_version: u8) {
+                self.$upgrade(old_version)
+            }
+        });
+    };
+    ($account:ty, $current:expr, $legacy:ty) => {
+        $crate::states::impl_versioned!(@impl $account, $current, $legacy, {});
+    };
+    (@impl $account:ty, $current:expr, $legacy:ty, { $($upgrade:tt)* }) => {
+        impl $crate::states::Versioned for $account {
+            const CURRENT_VERSION: u8 = $current;
+
+            type Legacy = $legacy;
+
+            fn from_legacy(legacy: $legacy) -> Self {
+                Self::from(legacy)
+            }
+
+            fn version(&self) -> u8 {
+                self.version
+            }
+
+            fn set_version(&mut self, version: u8) {
+                self.version = version;
+            }
+
+            $($upgrade)*
+        }
+    };
+}
+
+pub(crate) use impl_versioned;
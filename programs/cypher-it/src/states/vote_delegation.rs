@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct VoteDelegation {
+    pub channel_id: u64,
+    pub delegator: Pubkey,
+    pub delegate: Pubkey, // Votes with the delegator's weight unless the delegator votes directly
+    pub created_at: i64,
+    pub bump: u8,
+}
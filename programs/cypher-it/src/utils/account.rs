@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+// Same steps as Anchor's `init`, which tolerate a pre-funded address. Used where the
+// account may or may not exist yet, so it can't be declared with `init`.
+pub fn create_pda_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    signer_seeds: &[&[u8]],
+    space: usize,
+) -> Result<()> {
+    let rent_due = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(account.lamports());
+
+    if rent_due > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            rent_due,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Allocate { account_to_allocate: account.clone() },
+            &[signer_seeds],
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Assign { account_to_assign: account.clone() },
+            &[signer_seeds],
+        ),
+        &crate::ID,
+    )
+}
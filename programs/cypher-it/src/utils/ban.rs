@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use crate::constants::ANCHOR_DISCRIMINATOR_SIZE;
+use crate::errors::ErrorCode;
+use crate::states::Ban;
+use crate::utils::create_pda_account;
+
+pub fn read_ban(ban: &AccountInfo) -> Result<Option<Ban>> {
+    if ban.owner != &crate::ID || ban.data_is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(Ban::try_deserialize(&mut &ban.try_borrow_data()?[..])?))
+}
+
+// Ban PDAs are passed by address whether or not they exist, so a client can't dodge the check by omitting one
+pub fn ensure_not_banned(ban: &AccountInfo, current_time: i64) -> Result<()> {
+    if let Some(ban) = read_ban(ban)? {
+        if ban.is_active(current_time) {
+            return Err(ErrorCode::WalletBanned.into());
+        }
+    }
+    Ok(())
+}
+
+pub fn ban_expiry(current_time: i64, duration: i64) -> Result<i64> {
+    if duration < 0 {
+        return Err(ErrorCode::InvalidBanDuration.into());
+    }
+    if duration == 0 {
+        return Ok(0);
+    }
+    current_time
+        .checked_add(duration)
+        .ok_or(ErrorCode::ArithmeticOverflow.into())
+}
+
+// Creates the Ban PDA, or overwrites an existing one so repeat offenders get a fresh expiry
+#[allow(clippy::too_many_arguments)]
+pub fn write_ban<'info>(
+    ban: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    signer_seeds: &[&[u8]],
+    channel_id: u64,
+    wallet: Pubkey,
+    banned_by: Pubkey,
+    expires_at: i64,
+) -> Result<()> {
+    let bump = *signer_seeds.last().and_then(|bump| bump.first()).ok_or(ErrorCode::InvalidBan)?;
+
+    if ban.data_is_empty() {
+        create_pda_account(
+            ban,
+            payer,
+            system_program,
+            signer_seeds,
+            ANCHOR_DISCRIMINATOR_SIZE + Ban::INIT_SPACE,
+        )?;
+    } else if ban.owner != &crate::ID {
+        return Err(ErrorCode::InvalidBan.into());
+    }
+
+    let state = Ban {
+        channel_id,
+        wallet,
+        banned_by,
+        created_at: Clock::get()?.unix_timestamp,
+        expires_at,
+        bump,
+    };
+    state.try_serialize(&mut &mut ban.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
@@ -1,12 +1,15 @@
+use crate::constants::BPS_DENOMINATOR;
 use crate::errors::ErrorCode;
 use anchor_lang::prelude::*;
 
-pub fn calculate_platform_fee(amount: u64, fee_percentage: u64) -> Result<(u64, u64)> {
-    let platform_fee = amount
-        .checked_mul(fee_percentage)
+pub fn calculate_platform_fee(amount: u64, fee_bps: u64) -> Result<(u64, u64)> {
+    // Widen so large costs don't overflow before the division
+    let platform_fee = (amount as u128)
+        .checked_mul(fee_bps as u128)
         .ok_or(ErrorCode::ArithmeticOverflow)?
-        .checked_div(100)
+        .checked_div(BPS_DENOMINATOR as u128)
         .ok_or(ErrorCode::ArithmeticOverflow)?;
+    let platform_fee = u64::try_from(platform_fee).map_err(|_| ErrorCode::ArithmeticOverflow)?;
     
     let creator_amount = amount
         .checked_sub(platform_fee)
@@ -0,0 +1,22 @@
+use anchor_lang::solana_program::hash::hashv;
+
+// Distinct prefixes keep an inner node from being passed off as a leaf
+const LEAF_PREFIX: &[u8] = &[0];
+const NODE_PREFIX: &[u8] = &[1];
+
+// Leaves commit to the message's position in the channel as well as its content
+pub fn message_leaf(sequence: u64, message_hash: &[u8; 32]) -> [u8; 32] {
+    hashv(&[LEAF_PREFIX, &sequence.to_le_bytes(), message_hash]).to_bytes()
+}
+
+// Pairs are hashed in sorted order so proofs don't need direction bits
+pub fn verify_merkle_proof(root: &[u8; 32], leaf: [u8; 32], proof: &[[u8; 32]]) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| {
+        if node <= *sibling {
+            hashv(&[NODE_PREFIX, &node, sibling]).to_bytes()
+        } else {
+            hashv(&[NODE_PREFIX, sibling, &node]).to_bytes()
+        }
+    });
+    computed == *root
+}
@@ -0,0 +1,335 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::constants::ANCHOR_DISCRIMINATOR_SIZE;
+use crate::errors::ErrorCode;
+use crate::states::Versioned;
+
+// Grows an account to its current layout, then stamps the current version
+pub fn migrate_account<'info, T: Versioned>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+) -> Result<()> {
+    if account.owner != &crate::ID {
+        return Err(ErrorCode::InvalidMigrationAccount.into());
+    }
+
+    // Decode before resizing: the legacy layout is recognized by its allocated size
+    let (mut state, old_version) = decode_for_migration::<T>(&account.try_borrow_data()?)?;
+    state.upgrade_from(old_version);
+    state.set_version(T::CURRENT_VERSION);
+
+    if account.data_len() < space {
+        let rent_due = Rent::get()?
+            .minimum_balance(space)
+            .saturating_sub(account.lamports());
+
+        if rent_due > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    system_program.clone(),
+                    system_program::Transfer {
+                        from: payer.clone(),
+                        to: account.clone(),
+                    },
+                ),
+                rent_due,
+            )?;
+        }
+
+        account.resize(space)?;
+    }
+
+    encode_migrated(&state, &mut account.try_borrow_mut_data()?)?;
+
+    emit!(AccountMigrated {
+        account: account.key(),
+        old_version,
+        new_version: T::CURRENT_VERSION,
+    });
+
+    Ok(())
+}
+
+// Accounts from before versioning still have their original allocation and are
+// mapped field by field from the legacy layout. Versioned layouts only ever carve
+// new fields out of `reserved`, which is part of the serialized payload, so they
+// deserialize directly.
+pub fn decode_for_migration<T: Versioned>(data: &[u8]) -> Result<(T, u8)> {
+    if data.len() < ANCHOR_DISCRIMINATOR_SIZE || &data[..ANCHOR_DISCRIMINATOR_SIZE] != T::DISCRIMINATOR {
+        return Err(ErrorCode::InvalidMigrationAccount.into());
+    }
+
+    if data.len() == ANCHOR_DISCRIMINATOR_SIZE + T::Legacy::INIT_SPACE {
+        let legacy = T::Legacy::deserialize(&mut &data[ANCHOR_DISCRIMINATOR_SIZE..])?;
+        return Ok((T::from_legacy(legacy), 0));
+    }
+
+    let state = T::try_deserialize(&mut &data[..])?;
+    let version = state.version();
+    if version >= T::CURRENT_VERSION {
+        return Err(ErrorCode::AccountAlreadyMigrated.into());
+    }
+
+    Ok((state, version))
+}
+
+pub fn encode_migrated<T: Versioned>(state: &T, data: &mut [u8]) -> Result<()> {
+    let written = {
+        let mut cursor = std::io::Cursor::new(&mut data[..]);
+        state.try_serialize(&mut cursor)?;
+        cursor.position() as usize
+    };
+    // Clear leftovers from strings that have since shrunk
+    data[written..].fill(0);
+    Ok(())
+}
+
+#[event]
+pub struct AccountMigrated {
+    pub account: Pubkey,
+    pub old_version: u8,
+    pub new_version: u8,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::{
+        CHANNEL_VERSION,
+        CONFIG_VERSION,
+        MEMBERSHIP_VERSION,
+        POLL_VERSION,
+        PROFILE_VERSION,
+        PROTECTED_MODERATORS,
+    };
+    use crate::states::{
+        Channel,
+        ChannelV0,
+        Config,
+        ConfigV0,
+        Membership,
+        MembershipV0,
+        Poll,
+        PollV0,
+        Profile,
+        ProfileV0,
+    };
+
+    // Lays out an account as the old program allocated it, with `junk` after the
+    // payload the way a shortened string leaves stale bytes behind
+    fn legacy_account<T: Versioned>(legacy: &impl AnchorSerialize, junk: u8) -> Vec<u8> {
+        let mut data = vec![junk; ANCHOR_DISCRIMINATOR_SIZE + T::Legacy::INIT_SPACE];
+        data[..ANCHOR_DISCRIMINATOR_SIZE].copy_from_slice(T::DISCRIMINATOR);
+        let payload = legacy.try_to_vec().unwrap();
+        data[ANCHOR_DISCRIMINATOR_SIZE..ANCHOR_DISCRIMINATOR_SIZE + payload.len()].copy_from_slice(&payload);
+        data
+    }
+
+    // Runs the decode/resize/encode steps of migrate_account and reloads the result
+    fn migrate<T: Versioned + Space>(data: &[u8]) -> (T, u8) {
+        let (mut state, old_version) = decode_for_migration::<T>(data).unwrap();
+        state.upgrade_from(old_version);
+        state.set_version(T::CURRENT_VERSION);
+        let mut resized = data.to_vec();
+        resized.resize(ANCHOR_DISCRIMINATOR_SIZE + T::INIT_SPACE, 0xAA);
+        encode_migrated(&state, &mut resized).unwrap();
+        (T::try_deserialize(&mut &resized[..]).unwrap(), old_version)
+    }
+
+    #[test]
+    fn legacy_channel_ignores_stale_bytes_and_gets_defaults() {
+        let creator = Pubkey::new_unique();
+        let legacy = ChannelV0 {
+            id: 7,
+            name: "dev".to_string(),
+            description: "renamed to something short".to_string(),
+            cost: 1_000_000,
+            creator,
+            is_private: true,
+            member_count: 12,
+            created_at: 100,
+            updated_at: 200,
+            img_url: "https://example.com/a.png".to_string(),
+            poll_count: 3,
+        };
+
+        let (channel, old_version) = migrate::<Channel>(&legacy_account::<Channel>(&legacy, 0xFF));
+
+        assert_eq!(old_version, 0);
+        assert_eq!(channel.version, CHANNEL_VERSION);
+        assert_eq!(channel.id, 7);
+        assert_eq!(channel.name, "dev");
+        assert_eq!(channel.description, "renamed to something short");
+        assert_eq!(channel.creator, creator);
+        assert!(channel.is_private);
+        assert_eq!(channel.member_count, 12);
+        assert_eq!(channel.img_url, "https://example.com/a.png");
+        assert_eq!(channel.poll_count, 3);
+        assert!(channel.moderators.is_empty());
+        assert_eq!(channel.fee_override_bps, None);
+        assert_eq!(channel.join_count, 12);
+        assert_eq!(channel.protected_roles, PROTECTED_MODERATORS);
+        assert_eq!(channel.open_polls, 0);
+        assert_eq!(channel.reserved, [0; 27]);
+    }
+
+    #[test]
+    fn versioned_channel_before_protected_roles_protects_moderators() {
+        let legacy = ChannelV0 {
+            id: 7,
+            name: "dev".to_string(),
+            description: String::new(),
+            cost: 1_000_000,
+            creator: Pubkey::new_unique(),
+            is_private: false,
+            member_count: 2,
+            created_at: 100,
+            updated_at: 100,
+            img_url: String::new(),
+            poll_count: 0,
+        };
+        let mut channel = Channel::from(legacy);
+        channel.version = 2;
+        channel.protected_roles = 0;
+
+        let mut data = vec![0; ANCHOR_DISCRIMINATOR_SIZE + Channel::INIT_SPACE];
+        encode_migrated(&channel, &mut data).unwrap();
+        let (channel, old_version) = migrate::<Channel>(&data);
+
+        assert_eq!(old_version, 2);
+        assert_eq!(channel.version, CHANNEL_VERSION);
+        assert_eq!(channel.protected_roles, PROTECTED_MODERATORS);
+    }
+
+    #[test]
+    fn legacy_config_keeps_the_owner_as_guardian() {
+        let owner = Pubkey::new_unique();
+        let legacy = ConfigV0 {
+            initialized: true,
+            total_channels: 42,
+            platform_fee: 5,
+            fee_recipient: Pubkey::new_unique(),
+            owner,
+        };
+
+        let (config, old_version) = migrate::<Config>(&legacy_account::<Config>(&legacy, 0));
+
+        assert_eq!(old_version, 0);
+        assert_eq!(config.version, CONFIG_VERSION);
+        assert_eq!(config.total_channels, 42);
+        assert_eq!(config.platform_fee, 5);
+        assert_eq!(config.owner, owner);
+        assert_eq!(config.guardian, owner);
+        assert_eq!(config.pending_owner, None);
+        assert_eq!(config.paused, 0);
+    }
+
+    #[test]
+    fn legacy_membership_round_trips() {
+        let member = Pubkey::new_unique();
+        let nft_mint = Pubkey::new_unique();
+        let legacy = MembershipV0 {
+            channel_id: 7,
+            member,
+            joined: true,
+            joined_at: 300,
+            nft_mint: Some(nft_mint),
+        };
+
+        let (membership, _) = migrate::<Membership>(&legacy_account::<Membership>(&legacy, 0));
+
+        assert_eq!(membership.version, MEMBERSHIP_VERSION);
+        assert_eq!(membership.member, member);
+        assert!(membership.joined);
+        assert_eq!(membership.joined_at, 300);
+        assert_eq!(membership.nft_mint, Some(nft_mint));
+        assert_eq!(membership.muted_until, 0);
+        assert_eq!(membership.targeted_until, 0);
+    }
+
+    #[test]
+    fn legacy_poll_starts_settled() {
+        let voter = Pubkey::new_unique();
+        let legacy = PollV0 {
+            channel_id: 7,
+            creator: Pubkey::new_unique(),
+            poll_question: "Ship it?".to_string(),
+            poll_type: 2,
+            options: vec!["Yes".to_string(), "No".to_string()],
+            votes: vec![1, 0],
+            total_votes: 1,
+            start_time: 10,
+            end_time: 20,
+            required_votes: 0,
+            target: None,
+            voted: vec![voter],
+            ended: true,
+            bump: 254,
+        };
+
+        let (poll, _) = migrate::<Poll>(&legacy_account::<Poll>(&legacy, 0xFF));
+
+        assert_eq!(poll.version, POLL_VERSION);
+        assert_eq!(poll.poll_question, "Ship it?");
+        assert_eq!(poll.options, vec!["Yes".to_string(), "No".to_string()]);
+        assert_eq!(poll.votes, vec![1, 0]);
+        assert_eq!(poll.voted, vec![voter]);
+        assert!(poll.ended);
+        assert_eq!(poll.bump, 254);
+        assert_eq!(poll.deposit, 0);
+        assert!(poll.deposit_settled);
+    }
+
+    #[test]
+    fn legacy_profile_round_trips() {
+        let owner = Pubkey::new_unique();
+        let legacy = ProfileV0 {
+            owner,
+            display_name: "bob".to_string(),
+            bio: "hi".to_string(),
+            avatar_uri: String::new(),
+            created_at: 5,
+        };
+
+        let (profile, _) = migrate::<Profile>(&legacy_account::<Profile>(&legacy, 0xFF));
+
+        assert_eq!(profile.version, PROFILE_VERSION);
+        assert_eq!(profile.owner, owner);
+        assert_eq!(profile.display_name, "bob");
+        assert_eq!(profile.bio, "hi");
+        assert_eq!(profile.created_at, 5);
+        assert_eq!(profile.reputation, 0);
+    }
+
+    #[test]
+    fn current_accounts_are_not_migrated_twice() {
+        let legacy = MembershipV0 {
+            channel_id: 7,
+            member: Pubkey::new_unique(),
+            joined: true,
+            joined_at: 300,
+            nft_mint: None,
+        };
+        let (membership, _) = migrate::<Membership>(&legacy_account::<Membership>(&legacy, 0));
+
+        let mut data = vec![0; ANCHOR_DISCRIMINATOR_SIZE + Membership::INIT_SPACE];
+        encode_migrated(&membership, &mut data).unwrap();
+
+        assert!(decode_for_migration::<Membership>(&data).is_err());
+    }
+
+    #[test]
+    fn other_account_types_are_rejected() {
+        let legacy = MembershipV0 {
+            channel_id: 7,
+            member: Pubkey::new_unique(),
+            joined: true,
+            joined_at: 300,
+            nft_mint: None,
+        };
+
+        assert!(decode_for_migration::<Channel>(&legacy_account::<Membership>(&legacy, 0)).is_err());
+    }
+}
@@ -1,5 +1,19 @@
 pub mod validation;
 pub mod math;
+pub mod merkle;
+pub mod username;
+pub mod reputation;
+pub mod nft;
+pub mod migration;
+pub mod ban;
+pub mod account;
 
 pub use validation::*;
 pub use math::*;
+pub use merkle::*;
+pub use username::*;
+pub use reputation::*;
+pub use nft::*;
+pub use migration::*;
+pub use ban::*;
+pub use account::*;
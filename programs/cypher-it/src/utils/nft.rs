@@ -0,0 +1,143 @@
+use anchor_lang::prelude::*;
+use anchor_spl::metadata::mpl_token_metadata::types::DataV2;
+use anchor_spl::metadata::{create_metadata_accounts_v3, CreateMetadataAccountsV3};
+use anchor_spl::token::{self, Approve, Burn, FreezeAccount, ThawAccount};
+use crate::constants::{
+    MAX_NFT_NAME_LENGTH,
+    MAX_NFT_URI_LENGTH,
+    MEMBERSHIP_NFT_SYMBOL,
+};
+use crate::errors::ErrorCode;
+
+// Cuts a string to at most `max` bytes without splitting a character
+fn truncate_utf8(value: &str, max: usize) -> &str {
+    if value.len() <= max {
+        return value;
+    }
+    let mut end = max;
+    while !value.is_char_boundary(end) {
+        end -= 1;
+    }
+    &value[..end]
+}
+
+// e.g. "dev-talk #42", shortened to fit Metaplex's 32-byte name limit
+pub fn membership_nft_name(channel_name: &str, join_number: u64) -> String {
+    let suffix = format!(" #{}", join_number);
+    let prefix = truncate_utf8(channel_name, MAX_NFT_NAME_LENGTH.saturating_sub(suffix.len()));
+    format!("{}{}", prefix, suffix)
+}
+
+// The channel PDA is mint, freeze and update authority. Unlike a Membership it is
+// never closed or re-keyed, so the program can still act on the NFT after a resale
+#[allow(clippy::too_many_arguments)]
+pub fn create_membership_metadata<'info>(
+    token_metadata_program: AccountInfo<'info>,
+    metadata: AccountInfo<'info>,
+    nft_mint: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    payer: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    rent: AccountInfo<'info>,
+    channel_name: &str,
+    img_url: &str,
+    join_number: u64,
+    authority_signer: &[&[&[u8]]],
+) -> Result<()> {
+    // A cut-off URL would point wallets at a broken image
+    if img_url.len() > MAX_NFT_URI_LENGTH {
+        return Err(ErrorCode::ImageUrlTooLong.into());
+    }
+
+    create_metadata_accounts_v3(
+        CpiContext::new_with_signer(
+            token_metadata_program,
+            CreateMetadataAccountsV3 {
+                metadata,
+                mint: nft_mint,
+                mint_authority: authority.clone(),
+                payer,
+                update_authority: authority,
+                system_program,
+                rent,
+            },
+            authority_signer,
+        ),
+        DataV2 {
+            name: membership_nft_name(channel_name, join_number),
+            symbol: MEMBERSHIP_NFT_SYMBOL.to_string(),
+            uri: img_url.to_string(),
+            seller_fee_basis_points: 0,
+            creators: None,
+            collection: None,
+            uses: None,
+        },
+        false, // No update path exists, so the metadata is fixed at mint
+        true,
+        None,
+    )
+}
+
+// Soulbound channels: the channel PDA becomes the NFT's delegate so it can burn
+// it on leave or kick, then freezes it so the holder cannot move it
+pub fn lock_membership_nft<'info>(
+    token_program: AccountInfo<'info>,
+    nft_mint: AccountInfo<'info>,
+    nft_token_account: AccountInfo<'info>,
+    owner: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    authority_signer: &[&[&[u8]]],
+) -> Result<()> {
+    token::approve(
+        CpiContext::new(
+            token_program.clone(),
+            Approve {
+                to: nft_token_account.clone(),
+                delegate: authority.clone(),
+                authority: owner,
+            },
+        ),
+        1,
+    )?;
+
+    token::freeze_account(CpiContext::new_with_signer(
+        token_program,
+        FreezeAccount {
+            account: nft_token_account,
+            mint: nft_mint,
+            authority,
+        },
+        authority_signer,
+    ))
+}
+
+pub fn burn_membership_nft<'info>(
+    token_program: AccountInfo<'info>,
+    nft_mint: AccountInfo<'info>,
+    nft_token_account: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    authority_signer: &[&[&[u8]]],
+) -> Result<()> {
+    token::thaw_account(CpiContext::new_with_signer(
+        token_program.clone(),
+        ThawAccount {
+            account: nft_token_account.clone(),
+            mint: nft_mint.clone(),
+            authority: authority.clone(),
+        },
+        authority_signer,
+    ))?;
+
+    token::burn(
+        CpiContext::new_with_signer(
+            token_program,
+            Burn {
+                mint: nft_mint,
+                from: nft_token_account,
+                authority,
+            },
+            authority_signer,
+        ),
+        1,
+    )
+}
@@ -0,0 +1,11 @@
+use crate::constants::MAX_TENURE_REPUTATION;
+use crate::states::{Membership, Profile};
+
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+// Stored reputation plus a capped bonus for each full day in the channel
+pub fn effective_reputation(profile: &Profile, membership: &Membership, current_time: i64) -> u64 {
+    let tenure_days = current_time.saturating_sub(membership.joined_at).max(0) / SECONDS_PER_DAY;
+    let tenure_bonus = (tenure_days as u64).min(MAX_TENURE_REPUTATION);
+    profile.reputation.saturating_add(tenure_bonus)
+}
@@ -0,0 +1,11 @@
+use anchor_lang::solana_program::hash::hash;
+
+// Handles are unique case-insensitively and ignoring surrounding whitespace
+pub fn normalize_username(name: &str) -> String {
+    name.trim().to_lowercase()
+}
+
+// Names can exceed the 32-byte seed limit, so the PDA is keyed by their hash
+pub fn username_seed(name: &str) -> [u8; 32] {
+    hash(normalize_username(name).as_bytes()).to_bytes()
+}
@@ -1,5 +1,15 @@
-use crate::constants::{MAX_CHANNEL_NAME_LENGTH, MAX_CHANNEL_DESCRIPTION_LENGTH, MIN_CHANNEL_COST};
+use crate::constants::{
+    MAX_AVATAR_URI_LENGTH,
+    MAX_BIO_LENGTH,
+    MAX_CHANNEL_DESCRIPTION_LENGTH,
+    MAX_CHANNEL_NAME_LENGTH,
+    MAX_DISPLAY_NAME_LENGTH,
+    MAX_FEE_TIERS,
+    MAX_PLATFORM_FEE_BPS,
+    MIN_CHANNEL_COST,
+};
 use crate::errors::ErrorCode;
+use crate::states::FeeTier;
 use anchor_lang::prelude::*;
 
 pub fn validate_channel_name(name: &str) -> Result<()> {
@@ -31,4 +41,46 @@ pub fn validate_platform_fee(fee: u64, max_fee: u64) -> Result<()> {
         return Err(ErrorCode::PlatformFeeExceedsMaximum.into());
     }
     Ok(())
-}
\ No newline at end of file
+}
+
+pub fn validate_fee_tiers(tiers: &[FeeTier]) -> Result<()> {
+    if tiers.len() > MAX_FEE_TIERS {
+        return Err(ErrorCode::TooManyFeeTiers.into());
+    }
+    for tier in tiers {
+        validate_platform_fee(tier.fee_bps, MAX_PLATFORM_FEE_BPS)?;
+    }
+    if tiers.windows(2).any(|pair| pair[0].min_cost >= pair[1].min_cost) {
+        return Err(ErrorCode::FeeTiersNotSorted.into());
+    }
+    Ok(())
+}
+
+pub fn validate_display_name(display_name: &str) -> Result<()> {
+    if display_name.trim().is_empty() {
+        return Err(ErrorCode::EmptyDisplayName.into());
+    }
+    if display_name.len() > MAX_DISPLAY_NAME_LENGTH {
+        return Err(ErrorCode::DisplayNameTooLong.into());
+    }
+    // Letters, digits, '_', '-', '.' and inner spaces keep handles readable in poll questions
+    let allowed = |c: char| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | ' ');
+    if !display_name.chars().all(allowed) || display_name.trim() != display_name {
+        return Err(ErrorCode::InvalidDisplayName.into());
+    }
+    Ok(())
+}
+
+pub fn validate_bio(bio: &str) -> Result<()> {
+    if bio.len() > MAX_BIO_LENGTH {
+        return Err(ErrorCode::BioTooLong.into());
+    }
+    Ok(())
+}
+
+pub fn validate_avatar_uri(avatar_uri: &str) -> Result<()> {
+    if avatar_uri.len() > MAX_AVATAR_URI_LENGTH {
+        return Err(ErrorCode::AvatarUriTooLong.into());
+    }
+    Ok(())
+}